@@ -0,0 +1,264 @@
+use anyhow::Result;
+use axum::{extract::State, response::IntoResponse};
+use fantoccini::Client;
+use std::time::Duration;
+
+use crate::{
+    handlers::{
+        legacy_kepco::{
+            kepco::{
+                self, DATA_BOX, kepco_open_detail, kepco_open_login, kepco_search_customer,
+                kepco_submit_login,
+            },
+            pp_kepco::{self, CLAIM_GRID, go_to_monthly_claim},
+        },
+        pp::{
+            bill::{close_bill_detail, open_bill_detail},
+            commons::{pp_select_customer, pp_sign_in, pp_wait_loading},
+        },
+    },
+    models::{
+        driver::{
+            canary::{PageCheck, SelectorCheck, SelectorStatus, SiteCheck},
+            selectors::Selector,
+        },
+        error::response_errors_def::ErrorResponseCode,
+        handler::pp::{
+            commons::{PP_INTRO, PP_SELECT_CHARGE, PP_USER_INFO, PpRequestBody},
+            demand::PP_DEMAND_HISTORY,
+            load_profile::PP_REALTIME_USAGE,
+            usage::PP_DAILY_USAGE,
+        },
+        response::commons::basic_response,
+    },
+    server_init::app_state::AppState,
+    utils::{
+        drivers::go_to_url,
+        progress::ProgressReporter,
+        selectors::{selector, selector_registry},
+        sites::page_url,
+    },
+};
+
+const PP_SITE: &str = "pp";
+const KEPCO_ON_SITE: &str = "kepco_on";
+
+// selector drift 점검 즉시 실행
+pub async fn post_canary_handler(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let report = state.canary.run(&state.pool).await?;
+
+    Ok(basic_response(report, start.elapsed()))
+}
+
+// 마지막 점검 결과
+pub async fn get_canary_latest_handler(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let report = state
+        .canary
+        .latest()
+        .ok_or(ErrorResponseCode::CANARY_REPORT_NOT_FOUND)?;
+
+    Ok(basic_response(report, start.elapsed()))
+}
+
+// 크롤러가 거치는 페이지를 순서대로 방문하며 등록된 selector 확인
+pub async fn canary_sites(client: &Client, account: &PpRequestBody) -> Vec<SiteCheck> {
+    let mut pp = SiteCheck::new(PP_SITE);
+    if let Err(e) = canary_pp(client, account, &mut pp).await {
+        eprintln!("Canary stopped on {}: {:?}", PP_SITE, e);
+        pp.error = Some(format!("{:?}", e));
+    }
+    pp.skip_unvisited();
+
+    let mut kepco_on = SiteCheck::new(KEPCO_ON_SITE);
+    if let Err(e) = canary_kepco_on(client, account, &mut kepco_on).await {
+        eprintln!("Canary stopped on {}: {:?}", KEPCO_ON_SITE, e);
+        kepco_on.error = Some(format!("{:?}", e));
+    }
+    kepco_on.skip_unvisited();
+
+    vec![pp, kepco_on]
+}
+
+async fn canary_pp(client: &Client, account: &PpRequestBody, site: &mut SiteCheck) -> Result<()> {
+    let progress = ProgressReporter::none();
+
+    go_to_url(client, &page_url(PP_INTRO)?).await?;
+    site.check_page(client, "intro", &[]).await;
+
+    pp_sign_in(client, &account.userId, &account.userPw, &progress).await?;
+    site.check_page(client, "common", &[("user_num", &account.userNum)])
+        .await;
+
+    pp_select_customer(client, &account.userNum, &progress).await?;
+    go_to_url(client, &page_url(PP_USER_INFO)?).await?;
+    site.check_page(client, "user_info", &[]).await;
+
+    go_to_url(client, &page_url(PP_SELECT_CHARGE)?).await?;
+    pp_wait_loading(client, Duration::from_secs(15)).await?;
+    site.check_page(client, "select_charge", &[]).await;
+
+    go_to_monthly_claim(client).await?;
+    let rows = pp_kepco::get_children_ids_to_map(client, &selector(CLAIM_GRID)?).await?;
+    let row = rows.iter().next().map(|entry| entry.key().clone());
+    site.check_page(
+        client,
+        "monthly_claim",
+        &[("row", row.as_deref().unwrap_or_default())],
+    )
+    .await;
+
+    // 청구 내역이 없는 계정이면 상세 레이어는 skipped 로 남음
+    if let Some(row) = &row {
+        open_bill_detail(client, row).await?;
+        site.check_page(client, "bill_detail", &[]).await;
+        close_bill_detail(client).await?;
+    }
+
+    for (page, url) in [
+        ("realtime_usage", PP_REALTIME_USAGE),
+        ("daily_usage", PP_DAILY_USAGE),
+        ("demand_history", PP_DEMAND_HISTORY),
+    ] {
+        go_to_url(client, &page_url(url)?).await?;
+        pp_wait_loading(client, Duration::from_secs(15)).await?;
+        site.check_page(client, page, &[]).await;
+    }
+
+    Ok(())
+}
+
+async fn canary_kepco_on(
+    client: &Client,
+    account: &PpRequestBody,
+    site: &mut SiteCheck,
+) -> Result<()> {
+    kepco_open_login(client).await?;
+    site.check_page(client, "header", &[]).await;
+    site.check_page(client, "login", &[]).await;
+
+    kepco_submit_login(client, account).await?;
+    kepco_search_customer(client, &account.userNum).await?;
+    kepco_open_detail(client).await?;
+    let boxes = kepco::get_children_ids_to_map(client, &selector(DATA_BOX)?).await?;
+    let parent = boxes.iter().next().map(|entry| entry.key().clone());
+    site.check_page(
+        client,
+        "claim",
+        &[("parent", parent.as_deref().unwrap_or_default())],
+    )
+    .await;
+
+    Ok(())
+}
+
+impl SiteCheck {
+    fn new(site: &str) -> Self {
+        let version = selector_registry()
+            .snapshot()
+            .sites
+            .get(site)
+            .map(|selectors| selectors.version.clone());
+
+        SiteCheck {
+            site: site.to_string(),
+            version,
+            error: None,
+            pages: Vec::new(),
+        }
+    }
+
+    // 빈 값은 채우지 않음. 남은 placeholder 가 있으면 Skipped
+    async fn check_page(&mut self, client: &Client, page: &str, values: &[(&str, &str)]) {
+        let mut selectors = Vec::new();
+        for registered in selector_registry().snapshot().page(&self.site, page) {
+            let filled = values
+                .iter()
+                .filter(|(_, value)| !value.is_empty())
+                .fold(registered, |selector, (name, value)| {
+                    selector.fill(name, value)
+                });
+            selectors.push(check_selector(client, &filled).await);
+        }
+
+        self.pages.push(PageCheck {
+            page: page.to_string(),
+            visited: true,
+            url: client.current_url().await.ok().map(|url| url.to_string()),
+            selectors,
+        });
+    }
+
+    // 도달하지 못한 페이지도 보고서에 포함
+    fn skip_unvisited(&mut self) {
+        let snapshot = selector_registry().snapshot();
+        let Some(registered) = snapshot.sites.get(&self.site) else {
+            return;
+        };
+
+        for page in registered.pages.keys() {
+            if self.pages.iter().any(|checked| &checked.page == page) {
+                continue;
+            }
+            self.pages.push(PageCheck {
+                page: page.clone(),
+                visited: false,
+                url: None,
+                selectors: snapshot
+                    .page(&self.site, page)
+                    .into_iter()
+                    .map(|selector| SelectorCheck {
+                        key: selector.key,
+                        status: SelectorStatus::Skipped,
+                        matched: None,
+                    })
+                    .collect(),
+            });
+        }
+    }
+}
+
+async fn check_selector(client: &Client, selector: &Selector) -> SelectorCheck {
+    let unfilled = selector
+        .alternatives
+        .iter()
+        .any(|spec| spec.value.contains('{') && spec.value.contains('}'));
+    if unfilled {
+        return SelectorCheck {
+            key: selector.key.clone(),
+            status: SelectorStatus::Skipped,
+            matched: None,
+        };
+    }
+
+    for (i, spec) in selector.alternatives.iter().enumerate() {
+        if client.find(spec.locator()).await.is_ok() {
+            if i > 0 {
+                eprintln!("Canary: {} matched fallback #{}: {}", selector.key, i, spec);
+            }
+            return SelectorCheck {
+                key: selector.key.clone(),
+                status: if i == 0 {
+                    SelectorStatus::Ok
+                } else {
+                    SelectorStatus::Fallback
+                },
+                matched: Some(spec.to_string()),
+            };
+        }
+    }
+
+    eprintln!("Canary: {} is missing", selector.key);
+    SelectorCheck {
+        key: selector.key.clone(),
+        status: SelectorStatus::Missing,
+        matched: None,
+    }
+}
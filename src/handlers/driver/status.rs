@@ -0,0 +1,9 @@
+use crate::{models::response::commons::basic_response, server_init::app_state::AppState};
+use axum::{extract::State, response::IntoResponse};
+
+// chromedriver supervisor 상태 조회
+pub async fn get_driver_status_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let start = std::time::Instant::now();
+
+    basic_response(state.driver.status(), start.elapsed())
+}
@@ -0,0 +1,228 @@
+use axum::{
+    Extension, Json,
+    extract::{
+        Path, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use fantoccini::Client;
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::{
+    handlers::{
+        legacy_kepco::{
+            kepco::crawl_kepco_3year_data,
+            pp_kepco::{crawl_pp_all_periods_paid_data, crawl_pp_latest_paid_data},
+        },
+        pp::{
+            batch::{charge_listed_customers, run_pp_batch},
+            bill::crawl_pp_bill_breakdown,
+            customers::crawl_pp_customers,
+            demand::crawl_pp_demand_history,
+            load_profile::crawl_pp_load_profile,
+            usage::crawl_pp_usage_aggregates,
+            user_info::crawl_user_info,
+        },
+    },
+    models::{
+        auth::api_keys::{API_KEY_PROTOCOL, ClientIdentity},
+        error::response_errors_def::ErrorResponseCode,
+        handler::jobs::{
+            crawl_jobs::{CrawlJob, CrawlJobRequest},
+            progress::ProgressEvent,
+        },
+        response::commons::{basic_response, to_json_value},
+    },
+    server_init::app_state::AppState,
+    utils::{
+        artifacts::ArtifactStore,
+        auth::{CrawlPermit, CrawlQuota, api_key, crawl_rejection},
+        drivers::SessionPool,
+        progress::ProgressReporter,
+    },
+};
+
+// 크롤링 job 등록. job id 를 바로 반환하고 실제 크롤링은 백그라운드에서 진행.
+// 동시 실행 슬롯은 job 이 끝날 때까지 잡고 있음
+pub async fn submit_crawl_job_handler(
+    State(state): State<AppState>,
+    Extension(client): Extension<ClientIdentity>,
+    headers: HeaderMap,
+    Json(request): Json<CrawlJobRequest>,
+) -> Result<impl IntoResponse, Response> {
+    let start = std::time::Instant::now();
+
+    let key =
+        api_key(&headers).ok_or_else(|| ErrorResponseCode::INVALID_API_KEY.into_response())?;
+    let permit = state.clients.acquire_crawl(key).map_err(crawl_rejection)?;
+    // 고객번호 목록 배치는 등록 시점에 고객번호 수만큼 차감
+    if let CrawlJobRequest::PpBatch(params) = &request {
+        charge_listed_customers(&permit.quota(), params).map_err(crawl_rejection)?;
+    }
+
+    let pool = Arc::clone(&state.pool);
+    let artifacts = Arc::clone(&state.artifacts);
+    let job = state.jobs.submit(request.kind(), client, move |handle| {
+        run_crawl_job(pool, artifacts, request, handle, permit)
+    });
+
+    Ok((StatusCode::ACCEPTED, basic_response(job, start.elapsed())))
+}
+
+// 다른 클라이언트가 등록한 job 은 admin 이 아니면 JOB_NOT_FOUND
+pub async fn get_crawl_job_handler(
+    State(state): State<AppState>,
+    Extension(client): Extension<ClientIdentity>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let job = state
+        .jobs
+        .get(&id, &client)
+        .ok_or(ErrorResponseCode::JOB_NOT_FOUND)?;
+
+    Ok(basic_response(job, start.elapsed()))
+}
+
+// job 진행 상황 WebSocket 스트림. subprotocol 로 키를 보낸 경우 같은 protocol 을 응답해야
+// 브라우저가 연결을 유지함
+pub async fn crawl_job_progress_ws_handler(
+    State(state): State<AppState>,
+    Extension(client): Extension<ClientIdentity>,
+    Path(id): Path<Uuid>,
+    ws: WebSocketUpgrade,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let (job, events) = state
+        .jobs
+        .subscribe(&id, &client)
+        .ok_or(ErrorResponseCode::JOB_NOT_FOUND)?;
+
+    Ok(ws
+        .protocols([API_KEY_PROTOCOL])
+        .on_upgrade(move |socket| stream_job_progress(socket, job, events)))
+}
+
+pub async fn cancel_crawl_job_handler(
+    State(state): State<AppState>,
+    Extension(client): Extension<ClientIdentity>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let job = state
+        .jobs
+        .cancel(&id, &client)
+        .ok_or(ErrorResponseCode::JOB_NOT_FOUND)?;
+
+    Ok(basic_response(job, start.elapsed()))
+}
+
+async fn run_crawl_job(
+    pool: Arc<SessionPool>,
+    artifacts: Arc<ArtifactStore>,
+    request: CrawlJobRequest,
+    progress: ProgressReporter,
+    // job 이 끝나거나 취소되어 future 가 drop 될 때 슬롯 반환
+    permit: CrawlPermit,
+) -> Result<Value, ErrorResponseCode> {
+    let client = pool
+        .checkout(request.test_state())
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+    progress.report(ProgressEvent::SessionCheckedOut);
+
+    crawl_job_kind(&client, &artifacts, request, &permit.quota(), &progress).await
+}
+
+async fn crawl_job_kind(
+    client: &Client,
+    artifacts: &ArtifactStore,
+    request: CrawlJobRequest,
+    quota: &CrawlQuota,
+    progress: &ProgressReporter,
+) -> Result<Value, ErrorResponseCode> {
+    let result = match request {
+        CrawlJobRequest::PpUserInfo(params) => crawl_user_info(client, params, progress)
+            .await
+            .and_then(to_json_value),
+        CrawlJobRequest::PpAllPeriods(params) => {
+            crawl_pp_all_periods_paid_data(client, params, progress)
+                .await
+                .and_then(to_json_value)
+        }
+        CrawlJobRequest::PpLatest3(params) => crawl_pp_latest_paid_data(client, params, progress)
+            .await
+            .and_then(to_json_value),
+        CrawlJobRequest::KepcoThreeYear(params) => crawl_kepco_3year_data(client, params, progress)
+            .await
+            .and_then(to_json_value),
+        // 고객번호 수만큼의 차감이 artifact 를 남기는 구간 밖에 있도록 run_pp_batch 가 직접 감쌈
+        CrawlJobRequest::PpBatch(params) => {
+            return to_json_value(run_pp_batch(client, artifacts, params, quota, progress).await?);
+        }
+        CrawlJobRequest::PpCustomers(params) => crawl_pp_customers(client, params, progress)
+            .await
+            .and_then(to_json_value),
+        CrawlJobRequest::PpLoadProfile(params) => crawl_pp_load_profile(client, params, progress)
+            .await
+            .and_then(to_json_value),
+        CrawlJobRequest::PpUsageAggregates(params) => {
+            crawl_pp_usage_aggregates(client, params, progress)
+                .await
+                .and_then(to_json_value)
+        }
+        CrawlJobRequest::PpBillBreakdown(params) => {
+            crawl_pp_bill_breakdown(client, params, progress)
+                .await
+                .and_then(to_json_value)
+        }
+        CrawlJobRequest::PpDemandHistory(params) => {
+            crawl_pp_demand_history(client, params, progress)
+                .await
+                .and_then(to_json_value)
+        }
+    };
+
+    artifacts.capture_on_error(client, result).await
+}
+
+// job 진행 이벤트를 WebSocket 으로 전달. 종료 이벤트 이후 연결을 닫음
+async fn stream_job_progress(
+    mut socket: WebSocket,
+    job: CrawlJob,
+    mut events: broadcast::Receiver<ProgressEvent>,
+) {
+    // 현재까지의 마지막 이벤트 먼저 전달
+    if let Some(event) = &job.progress
+        && (send_event(&mut socket, event).await.is_err() || event.is_terminal())
+    {
+        let _ = socket.send(Message::Close(None)).await;
+        return;
+    }
+
+    loop {
+        let event = match events.recv().await {
+            Ok(event) => event,
+            // 느린 구독자는 밀린 이벤트를 건너뜀
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        if send_event(&mut socket, &event).await.is_err() || event.is_terminal() {
+            break;
+        }
+    }
+
+    let _ = socket.send(Message::Close(None)).await;
+}
+
+async fn send_event(socket: &mut WebSocket, event: &ProgressEvent) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(event).unwrap_or_default();
+    socket.send(Message::Text(text.into())).await
+}
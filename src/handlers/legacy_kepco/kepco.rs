@@ -1,723 +1,326 @@
-use anyhow::{Context, Result};
-use axum::{Json, http::StatusCode, response::IntoResponse};
+use anyhow::{Context, Result, anyhow};
+use axum::{Json, extract::State, response::IntoResponse};
 use chrono::{Datelike, NaiveDate};
 use dashmap::DashMap;
-use fantoccini::{Client, ClientBuilder, Locator, elements::Element};
-use serde_json::{Map, Value, json};
-use std::{
-    process::{Child, Command},
-    sync::Arc,
-};
+use fantoccini::{Client, Locator, elements::Element};
+use std::collections::HashSet;
+use std::sync::Arc;
 use tokio::time::{Duration, timeout};
 
-use crate::models::handler::{legacy_kepco::kepco_models::KepcoData, pp::commons::PpRequestBody};
+use crate::{
+    handlers::pp::commons::classify_login_message,
+    models::{
+        driver::{
+            dialog::{DIALOG_POLL_INTERVAL, DialogAction},
+            selectors::{Selector, SelectorKey},
+        },
+        error::{crawl_errors::CrawlError, response_errors_def::ErrorResponseCode},
+        handler::{
+            jobs::progress::ProgressEvent,
+            legacy_kepco::kepco_models::{KEPCO_ON_MAIN, KepcoData},
+            pp::commons::PpRequestBody,
+        },
+        response::commons::basic_response,
+    },
+    server_init::app_state::AppState,
+    utils::{drivers::*, progress::ProgressReporter, selectors::selector, sites::page_url},
+};
+
+const SITE_MAP_BUTTON: SelectorKey = SelectorKey::new("kepco_on", "header", "site_map_button");
+const LOGIN_MENU: SelectorKey = SelectorKey::new("kepco_on", "header", "login_menu");
+const USER_ID_INPUT: SelectorKey = SelectorKey::new("kepco_on", "login", "user_id");
+const USER_PW_INPUT: SelectorKey = SelectorKey::new("kepco_on", "login", "user_pw");
+const LOGIN_BUTTON: SelectorKey = SelectorKey::new("kepco_on", "login", "login_button");
+const CLAIM_MENU: SelectorKey = SelectorKey::new("kepco_on", "claim", "claim_menu");
+const PROCESS_MESSAGE: SelectorKey = SelectorKey::new("kepco_on", "claim", "process_message");
+const CUSTOMER_NUMBER_INPUT: SelectorKey = SelectorKey::new("kepco_on", "claim", "customer_number");
+const SEARCH_BUTTON: SelectorKey = SelectorKey::new("kepco_on", "claim", "search_button");
+const DETAIL_BUTTON: SelectorKey = SelectorKey::new("kepco_on", "claim", "detail_button");
+const ONE_YEAR_OPTION: SelectorKey = SelectorKey::new("kepco_on", "claim", "one_year_option");
+const YEAR_MONTH_SELECT: SelectorKey = SelectorKey::new("kepco_on", "claim", "year_month_select");
+pub const DATA_BOX: SelectorKey = SelectorKey::new("kepco_on", "claim", "data_box");
+const CLAIM_MONTH: SelectorKey = SelectorKey::new("kepco_on", "claim", "claim_month");
+const DATE_RANGE: SelectorKey = SelectorKey::new("kepco_on", "claim", "date_range");
+const USAGE: SelectorKey = SelectorKey::new("kepco_on", "claim", "usage");
+const AMOUNT: SelectorKey = SelectorKey::new("kepco_on", "claim", "amount");
+const PAID: SelectorKey = SelectorKey::new("kepco_on", "claim", "paid");
+const UNPAID: SelectorKey = SelectorKey::new("kepco_on", "claim", "unpaid");
+const PAYMENT_OPTION: SelectorKey = SelectorKey::new("kepco_on", "claim", "payment_option");
+const PAYMENT_METHOD: SelectorKey = SelectorKey::new("kepco_on", "claim", "payment_method");
+
+// 로그인 제출 후 팝업이 닫히거나 실패 alert 가 뜰 때까지 기다리는 시간
+const LOGIN_RESULT_TIMEOUT: Duration = Duration::from_secs(10);
 
 // 한전 3년치 요금 조회 고객번호 기준
 pub async fn get_3year_kepco_data_of_handler(
+    State(state): State<AppState>,
     Json(params): Json<PpRequestBody>,
-) -> impl IntoResponse {
-    let url = "http://localhost:4444";
-    let user_id = &params.userId;
-    let user_pw = &params.userPw;
-    let user_number = &params.userNum;
-
-    // driver path
-    let chromedriver_path = "src/driver/chromedriver";
-    let chrome_binary_path = "/usr/bin/google-chrome";
-
-    // driver 실행
-    let mut chromedriver_process = match Command::new(chromedriver_path)
-        .arg("--port=4444")
-        .arg(format!("--binary={}", chrome_binary_path))
-        .spawn()
-    {
-        Ok(process) => process,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Could not chromedriver_process!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
 
-    // driver 대기
-    tokio::time::sleep(Duration::from_secs(2)).await;
+    let client = state
+        .pool
+        .checkout(PpRequestBody::test_state(&params))
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
 
-    // headless, disable-gpu option
-    let capabilities: Map<String, Value> = match serde_json::from_value(json!({
-        "goog:chromeOptions": {
-            "binary": "/usr/bin/google-chrome",
-            "args": ["--headless", "--disable-gpu"]
-        }
-    })) {
-        Ok(result) => result,
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Could not acquire connection from pool!",
-            )
-                .into_response();
-        }
-    };
+    let data_vec = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_kepco_3year_data(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
 
-    let client = loop {
-        match ClientBuilder::native()
-            .capabilities(capabilities.clone())
-            .connect(url)
-            .await
-        {
-            Ok(client) => break client,
-            Err(e) => {
-                eprintln!("Retrying to connect to WebDriver: {}", e);
-                tokio::time::sleep(Duration::from_secs(1)).await;
-            }
-        }
-    };
-    let client_arc = Arc::new(client.clone());
-
-    // view size
-    match client_arc.set_window_rect(0, 0, 774, 857).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to set view size!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 페이지 이동
-    match client_arc.goto("https://online.kepco.co.kr").await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to navigate goto!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    Ok(basic_response(data_vec, start.elapsed()))
+}
 
-    // menu button 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("mf_wfm_header_gnb_btnSiteMap"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // menu button 클릭
-    match click_element(&client_arc, Locator::Id("mf_wfm_header_gnb_btnSiteMap")).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+pub async fn crawl_kepco_3year_data(
+    client: &Client,
+    params: PpRequestBody,
+    progress: &ProgressReporter,
+) -> Result<Vec<KepcoData>, ErrorResponseCode> {
+    kepco_login(client, &params)
+        .await
+        .map_err(|e| ErrorResponseCode::KEPCO_LOGIN.caused_by(e))?;
+    progress.report(ProgressEvent::LoggedIn);
 
-    // login form 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("mf_wfm_header_gnb_mobileGoLogin"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to wait_for_element!",
-            )
-                .into_response();
-        }
-    };
-    // login form 클릭
-    match click_element(&client_arc, Locator::Id("mf_wfm_header_gnb_mobileGoLogin")).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    kepco_3year_data(client, &params.userNum, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::KEPCO_PAID_DATA.caused_by(e))
+}
 
-    // id 입력 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("mf_wfm_header_gnb_login_popup_wframe_ui_id"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // id 입력
-    match enter_value_in_element(
-        &client_arc,
-        Locator::Id("mf_wfm_header_gnb_login_popup_wframe_ui_id"),
-        user_id,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to enter_value_in_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // pw 입력
-    match enter_value_in_element(
-        &client_arc,
-        Locator::Id("mf_wfm_header_gnb_login_popup_wframe_ui_pw"),
-        user_pw,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to enter_value_in_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 로그인 버튼 클릭
-    match click_element(
-        &client_arc,
-        Locator::Id("mf_wfm_header_gnb_login_popup_wframe_btn_login"),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+async fn kepco_login(client: &Client, params: &PpRequestBody) -> Result<()> {
+    kepco_open_login(client).await?;
+    kepco_submit_login(client, params).await?;
 
-    // 요금 조회 버튼 클릭 반복 시도
-    match click_element_with_retries(
-        &client_arc,
-        Locator::XPath("/html/body/div[2]/div[3]/div/div/div[4]/div/div[2]/div[1]/a[3]"),
-        10,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    println!("kepco_login successfully");
+    Ok(())
+}
 
-    // 필드 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("mf_wfm_layout_inp_searchCustNo"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+// 메인 화면에서 로그인 팝업까지 이동
+pub async fn kepco_open_login(client: &Client) -> Result<()> {
+    go_to_url(client, &page_url(KEPCO_ON_MAIN)?).await?;
+    ensure_not_in_maintenance(client).await?;
 
-    // 로딩 대기
-    match wait_for_element_hidden(
-        &client_arc,
-        Locator::Id("mf_wq_uuid_1_wq_processMsgComp"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_hidden!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    // menu button 클릭
+    let site_map_button = selector(SITE_MAP_BUTTON)?;
+    click_element(client, wait_selector(client, &site_map_button).await?).await?;
 
-    // 사용자 번호 입력
-    match enter_value_in_element(
-        &client_arc,
-        Locator::Id("mf_wfm_layout_inp_searchCustNo"),
-        user_number,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to enter_value_in_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 검색 버튼 클릭
-    match click_element(&client_arc, Locator::Id("mf_wfm_layout_btn_search")).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    // login form 클릭
+    let login_menu = selector(LOGIN_MENU)?;
+    click_element(client, wait_selector(client, &login_menu).await?).await?;
+
+    Ok(())
+}
 
-    // 상세 요금 버튼 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("mf_wfm_layout_ui_generator_0_btn_moveDetail"),
-        &mut chromedriver_process,
+pub async fn kepco_submit_login(client: &Client, params: &PpRequestBody) -> Result<()> {
+    // id, pw 입력
+    let user_id_input = selector(USER_ID_INPUT)?;
+    let user_pw_input = selector(USER_PW_INPUT)?;
+    enter_value_in_element(
+        client,
+        wait_selector(client, &user_id_input).await?,
+        &params.userId,
     )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 스크롤 강제 맨 아래
-    match client_arc
-        .execute("window.scrollTo(0, document.body.scrollHeight);", vec![])
-        .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to scrollHeight!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 상세 요금 버튼 클릭
-    match click_element(
-        &client_arc,
-        Locator::Id("mf_wfm_layout_ui_generator_0_btn_moveDetail"),
+    .await?;
+    enter_value_in_element(
+        client,
+        find_selector(client, &user_pw_input).await?,
+        &params.userPw,
     )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    // '1년' 옵션을 선택
-    match click_element_with_retries(&client_arc, Locator::XPath("//option[text()='1년']"), 10)
-        .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element_with_retries!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    .await?;
 
-    // 로딩 대기
-    match wait_for_element_hidden(
-        &client_arc,
-        Locator::Id("mf_wq_uuid_1_wq_processMsgComp"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_hidden!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    // 로그인 버튼 클릭
+    let login_button = selector(LOGIN_BUTTON)?;
+    click_element(client, find_selector(client, &login_button).await?).await?;
 
-    // 자식 요소 ID, DashMap 에 저장
-    let map = match get_children_ids_to_map(&client_arc, "mf_wfm_layout_ui_generator").await {
-        Ok(map) => map,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Could not get_children_ids_to_map!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    kepco_wait_login_result(client, &user_pw_input).await
+}
 
-    // data from parent_id -> vec
-    let mut data_vec = match parse_data_from_parent_ids(&client_arc, map).await {
-        Ok(vec) => vec,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Could not parse_data_from_parent_ids!: {:?}", e),
-            )
-                .into_response();
+// 로그인 실패 시 alert 로 사유가 표시됨. 팝업이 닫히거나 alert 가 뜰 때까지 대기
+async fn kepco_wait_login_result(client: &Client, user_pw_input: &Selector) -> Result<()> {
+    let result = timeout(LOGIN_RESULT_TIMEOUT, async {
+        loop {
+            if let Some(message) = handle_dialog(client, DialogAction::Accept).await? {
+                return Err(kepco_login_rejected(&message));
+            }
+            if kepco_login_popup_closed(client, user_pw_input).await? {
+                return Ok(());
+            }
+            tokio::time::sleep(DIALOG_POLL_INTERVAL).await;
         }
-    };
-    data_vec.sort_by(|a, b| b.claim_date.cmp(&a.claim_date));
+    })
+    .await;
 
-    let reference_date = data_vec[data_vec.len() - 1]
-        .claim_date
-        .map(|date| format!("{}년 {:02}월", date.year(), date.month()))
-        .unwrap_or_else(|| "N/A".to_string());
+    result.map_err(|e| {
+        eprintln!("Failed to wait kepco login result: {:?}", e);
+        CrawlError::NavigationTimeout(format!("Failed to wait kepco login result: {:?}", e))
+    })?
+}
 
-    // 뒤로 가기
-    match client_arc.back().await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to navigate back!: {:?}", e),
-            )
-                .into_response();
+// 비밀번호 입력란이 없거나 숨겨졌으면 로그인 팝업이 닫힌 것으로 봄
+async fn kepco_login_popup_closed(client: &Client, user_pw_input: &Selector) -> Result<bool> {
+    for spec in &user_pw_input.alternatives {
+        let displayed = match client.find(spec.locator()).await {
+            Ok(element) => element.is_displayed().await,
+            Err(e) if e.is_no_such_element() => continue,
+            Err(e) => Err(e),
+        };
+        match displayed {
+            Ok(false) => continue,
+            Ok(true) => return Ok(false),
+            Err(e) => match unexpected_alert_text(&e) {
+                Some(message) => return Err(kepco_login_rejected(&message)),
+                // 페이지 이동 중에는 요소 조회가 실패할 수 있으므로 다시 확인
+                None => return Ok(false),
+            },
         }
-    };
+    }
 
-    // select 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("mf_wfm_layout_slb_searchYm_input_0"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    Ok(true)
+}
 
-    // 로딩 대기
-    match wait_for_element_hidden(
-        &client_arc,
-        Locator::Id("mf_wq_uuid_1_wq_processMsgComp"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_hidden!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+fn kepco_login_rejected(message: &str) -> anyhow::Error {
+    let error = classify_login_message(message);
+    eprintln!("kepco_login rejected: {}", error);
+    error.into()
+}
 
-    // select 에서 reference_date 옵션의 인덱스 search
-    let select_locator = Locator::Id("mf_wfm_layout_slb_searchYm_input_0");
-    let mut option_index =
-        match get_option_index(&client_arc, select_locator, &reference_date).await {
-            Ok(u) => u,
-            Err(e) => {
-                return (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    format!("Failed to find option index!: {:?}", e),
-                )
-                    .into_response();
-            }
-        };
+// 요금 조회 화면으로 이동 후 고객번호 검색
+pub async fn kepco_search_customer(client: &Client, user_number: &str) -> Result<()> {
+    // 요금 조회 버튼 클릭 반복 시도
+    let claim_menu = selector(CLAIM_MENU)?;
+    click_element_with_retries(client, wait_selector(client, &claim_menu).await?, 10).await?;
 
-    option_index += 1;
+    let customer_number_input = selector(CUSTOMER_NUMBER_INPUT)?;
+    let search_button = selector(SEARCH_BUTTON)?;
+    wait_selector(client, &customer_number_input).await?;
+    kepco_wait_processing(client).await?;
 
-    // 1year over data parsing
-    let mut additional_data_vec = match parsing_options_data(
-        &client_arc,
-        select_locator,
+    // 사용자 번호 입력 후 검색
+    enter_value_in_element(
+        client,
+        find_selector(client, &customer_number_input).await?,
         user_number,
-        &option_index,
-        &mut chromedriver_process,
     )
-    .await
-    {
-        Ok(vec) => vec,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed parsing_options_data!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    // data 병합
-    data_vec.append(&mut additional_data_vec);
+    .await?;
+    click_element(client, find_selector(client, &search_button).await?).await?;
 
-    // 2분 동안 대기
-    // println!("Waiting for 2 minutes...");
-    // tokio::time::sleep(tokio::time::Duration::from_secs(120)).await;
+    Ok(())
+}
 
-    match client.delete_all_cookies().await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed delete_all_cookies!: {:?}", e),
-            )
-                .into_response();
-        }
-    }
+// 상세 요금 화면에서 1년치 조회
+pub async fn kepco_open_detail(client: &Client) -> Result<()> {
+    // 상세 요금 버튼 클릭. 잘못된 고객번호나 세션 만료는 alert 로 표시됨
+    let detail_button = selector(DETAIL_BUTTON)?;
+    let detail_locator = with_dialog_guard(
+        client,
+        DialogAction::Accept,
+        wait_selector(client, &detail_button),
+    )
+    .await?;
+    script_execute(client, "window.scrollTo(0, document.body.scrollHeight);").await?;
+    click_element(client, detail_locator).await?;
 
-    // ChromeDriver 프로세스 종료
-    match chromedriver_process.kill() {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed chromedriver_process.kill!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    // '1년' 옵션을 선택
+    let one_year_option = selector(ONE_YEAR_OPTION)?;
+    click_element_with_retries(client, wait_selector(client, &one_year_option).await?, 10).await?;
+    kepco_wait_processing(client).await?;
 
-    (StatusCode::OK, Json(data_vec)).into_response()
+    Ok(())
 }
 
-// 요소 대기
-async fn wait_for_element(
+async fn kepco_3year_data(
     client: &Client,
-    locator: Locator<'_>,
-    chromedriver_process: &mut Child,
-) -> Result<Option<Element>> {
-    match client.wait().for_element(locator).await {
-        Ok(element) => Ok(Some(element)),
-        Err(e) => {
-            eprintln!("Failed to find the element: {:?}\n {}", locator, e);
-            client
-                .clone()
-                .close()
-                .await
-                .context("Failed to close client")?;
-            chromedriver_process
-                .kill()
-                .expect("failed to kill ChromeDriver");
-            Err(anyhow::anyhow!("Failed to find the element: {:?}", e))
-        }
-    }
-}
+    user_number: &str,
+    progress: &ProgressReporter,
+) -> Result<Vec<KepcoData>> {
+    kepco_search_customer(client, user_number).await?;
+    progress.report(ProgressEvent::CustomerSelected {
+        user_num: user_number.to_string(),
+    });
+
+    kepco_open_detail(client).await?;
+
+    // 1년치 data
+    let map = get_children_ids_to_map(client, &selector(DATA_BOX)?).await?;
+    let mut data_vec = parse_data_from_parent_ids(client, map).await?;
+    dedup_and_sort_by_claim_date(&mut data_vec);
+    progress.report(ProgressEvent::RowsFound {
+        rows: data_vec.len(),
+    });
+
+    let reference_date = data_vec
+        .last()
+        .and_then(|data| data.claim_date)
+        .map(|date| format!("{}년 {:02}월", date.year(), date.month()))
+        .ok_or_else(|| anyhow!("No claim data found for the last year"))?;
 
-// 요소 클릭
-async fn click_element(client: &Client, locator: Locator<'_>) -> Result<()> {
-    if let Ok(element) = client.find(locator).await {
-        element
-            .click()
-            .await
-            .context(format!("Failed to click the element: {:?}", locator))?;
-        println!("Element clicked successfully: {:?}", locator);
-    } else {
-        eprintln!("Failed to find the element: {:?}", locator);
-        return Err(anyhow::anyhow!("Failed to find the element: {:?}", locator));
-    }
-    Ok(())
-}
+    // 뒤로 가기
+    client.back().await.map_err(|e| {
+        eprintln!("Failed to navigate back: {:?}", e);
+        anyhow!("Failed to navigate back: {:?}", e)
+    })?;
 
-// 요소에 값 입력
-async fn enter_value_in_element(client: &Client, locator: Locator<'_>, text: &str) -> Result<()> {
-    if let Ok(element) = client.find(locator).await {
-        if let Err(e) = element.send_keys(text).await {
-            eprintln!("Failed to enter text: {}", e);
-        } else {
-            println!("Text entered successfully: {:?}", locator);
-        }
-    } else {
-        eprintln!("Failed to find the input element: {:?}", locator);
-    }
-    Ok(())
-}
+    let year_month_select = selector(YEAR_MONTH_SELECT)?;
+    let select_locator = wait_selector(client, &year_month_select).await?;
+    kepco_wait_processing(client).await?;
 
-// 요소 비활성화 대기
-async fn wait_for_element_hidden(
-    client: &Client,
-    locator: Locator<'_>,
-    chromedriver_process: &mut Child,
-    duration: Duration,
-) -> Result<()> {
-    let element = match wait_for_element(client, locator, chromedriver_process).await? {
-        Some(element) => element,
-        None => return Err(anyhow::anyhow!("Failed to find the element: {:?}", locator)),
-    };
+    // select 에서 reference_date 다음 옵션부터 parsing
+    let option_index = option_index_by_text(client, select_locator, &reference_date).await? + 1;
 
-    let element_hidden = timeout(duration, async {
-        loop {
-            match element.attr("aria-hidden").await {
-                Ok(Some(value)) if value == "true" => {
-                    println!("Element is hidden (aria-hidden=\"true\")");
-                    break;
-                }
-                Ok(_) => {
-                    eprintln!("Element is not hidden, retrying...");
-                }
-                Err(e) => {
-                    eprintln!("Failed to get aria-hidden attribute: {}", e);
-                }
-            }
-            tokio::time::sleep(Duration::from_secs(1)).await;
-        }
-    })
-    .await;
+    // 1year over data parsing
+    let mut additional_data_vec =
+        parsing_options_data(client, select_locator, user_number, &option_index, progress).await?;
 
-    if element_hidden.is_err() {
-        Err(anyhow::anyhow!(
-            "Failed to find the element within the given duration"
-        ))
-    } else {
-        Ok(())
-    }
+    // data 병합. 1년치와 이전 월 조회 결과가 겹칠 수 있음
+    data_vec.append(&mut additional_data_vec);
+    dedup_and_sort_by_claim_date(&mut data_vec);
+
+    println!("kepco_3year_data successfully");
+    Ok(data_vec)
 }
 
-// 요소 클릭 반복
-async fn click_element_with_retries(
-    client: &Client,
-    locator: Locator<'_>,
-    max_attempts: u32,
-) -> Result<()> {
-    let mut attempts = 0;
-    loop {
-        if attempts >= max_attempts {
-            return Err(anyhow::anyhow!(
-                "Failed to click the element after {} attempts",
-                max_attempts
-            ));
-        }
-        match client.find(locator).await {
-            Ok(element) => match element.click().await {
-                Ok(_) => {
-                    println!(
-                        "Element clicked successfully after {} attempts",
-                        attempts + 1
-                    );
-                    return Ok(());
-                }
-                Err(e) => {
-                    eprintln!(
-                        "Failed to click the element (attempt {}): {}",
-                        attempts + 1,
-                        e
-                    );
-                    tokio::time::sleep(Duration::from_secs(1)).await;
-                    attempts += 1;
-                }
-            },
-            Err(e) => {
-                eprintln!(
-                    "Retrying to find the element (attempt {}): {}",
-                    attempts + 1,
-                    e
-                );
-                // 요소를 찾지 못하면 잠시 대기 후 다시 시도
-                tokio::time::sleep(Duration::from_secs(1)).await;
-                attempts += 1;
-            }
-        }
-    }
+// 중복 제거 후 최신순 정렬
+fn dedup_and_sort_by_claim_date(data_vec: &mut Vec<KepcoData>) {
+    let mut unique_dates = HashSet::new();
+    data_vec.retain(|entry| unique_dates.insert(entry.claim_date));
+
+    data_vec.sort_by_key(|entry| std::cmp::Reverse(entry.claim_date));
 }
 
-// select 요소에서 옵션 인덱스 찾기
-async fn get_option_index(
-    client: &Client,
-    select_locator: Locator<'_>,
-    text: &str,
-) -> Result<usize> {
-    let element = client
-        .find(select_locator)
-        .await
-        .context("Failed to find select element")?;
+// 행에서 찾지 못한 항목은 0 으로 채우지 않고 ParseFailure.
+// 특히 청구 월이 없으면 정렬 / 중복 제거가 틀어지므로 행 전체를 실패로 처리
+fn required_cell(cell: Option<String>, name: &str, parent_id: &str) -> Result<String> {
+    cell.ok_or_else(|| CrawlError::ParseFailure(format!("{} of '{}'", name, parent_id)).into())
+}
 
-    let options = element.find_all(Locator::XPath(".//option")).await?;
-    for (index, option) in options.iter().enumerate() {
-        if let Ok(option_text) = option.text().await {
-            if option_text == text {
-                return Ok(index);
-            }
-        }
-    }
-    Err(anyhow::anyhow!("Option with text '{}' not found", text))
+// 처리 중 메시지가 사라질 때까지 대기
+pub async fn kepco_wait_processing(client: &Client) -> Result<()> {
+    let process_message = selector(PROCESS_MESSAGE)?;
+    let locator = wait_selector(client, &process_message).await?;
+
+    wait_for_element_aria_hidden(client, locator, Duration::from_secs(20)).await
 }
 
 // 자식 요소들의 ID -> DashMap
-async fn get_children_ids_to_map(
+pub async fn get_children_ids_to_map(
     client: &Client,
-    parent_id: &str,
+    parent: &Selector,
 ) -> Result<Arc<DashMap<String, ()>>> {
-    let script = format!(
-        r#"
-        let children = document.getElementById('{}').children;
+    let parent = find_element(client, find_selector(client, parent).await?).await?;
+    let script = r#"
+        let children = arguments[0].children;
         let ids = [];
-        for (let i = 0; i < children.length; i++) {{
+        for (let i = 0; i < children.length; i++) {
             ids.push(children[i].id);
-        }}
+        }
         return ids;
-        "#,
-        parent_id
-    );
+        "#;
 
     let result = client
-        .execute(&script, vec![])
+        .execute(script, vec![serde_json::to_value(&parent)?])
         .await
         .context("Failed to execute script to get children IDs")?;
 
@@ -748,17 +351,17 @@ fn parse_date(date_str: &str) -> Result<NaiveDate> {
         date_str.to_string()
     };
 
-    NaiveDate::parse_from_str(&date_with_day, "%Y.%m.%d").context("Failed to parse date")
+    NaiveDate::parse_from_str(&date_with_day, "%Y.%m.%d")
+        .with_context(|| CrawlError::ParseFailure(format!("date '{}'", date_str)))
 }
 
 // parsing 대상 기간
-fn parse_date_range(date_range: &str) -> Result<(Option<NaiveDate>, Option<NaiveDate>)> {
-    let dates: Vec<&str> = date_range.split('-').collect();
-
-    let start_date = parse_date(dates[0]).ok();
-    let end_date = parse_date(dates[1]).ok();
+fn parse_date_range(date_range: &str) -> Result<(NaiveDate, NaiveDate)> {
+    let Some((start, end)) = date_range.split_once('-') else {
+        return Err(CrawlError::ParseFailure(format!("date range '{}'", date_range)).into());
+    };
 
-    Ok((start_date, end_date))
+    Ok((parse_date(start.trim())?, parse_date(end.trim())?))
 }
 
 // parsing 사용량
@@ -766,15 +369,17 @@ fn parse_use_kwh(kwh_str: &str) -> Result<f64> {
     let cleaned_str = kwh_str.replace(',', "").replace("kWh", "");
     cleaned_str
         .parse::<f64>()
-        .context("Failed to parse use kWh")
+        .with_context(|| CrawlError::ParseFailure(format!("use kWh '{}'", kwh_str)))
 }
 
 // parsing 요금
 fn parse_amount(amount_str: &str) -> Result<i64> {
     let amount_part = amount_str.split('원').next().unwrap_or(amount_str);
 
-    let amount = amount_part.replace(',', "").replace('.', "");
-    amount.parse::<i64>().context("Failed to parse amount")
+    let amount = amount_part.replace([',', '.'], "");
+    amount
+        .parse::<i64>()
+        .with_context(|| CrawlError::ParseFailure(format!("amount '{}'", amount_str)))
 }
 
 // parsing 지불 방법, 기간
@@ -788,10 +393,8 @@ fn parse_payment_method(payment_str: &str) -> Result<(Option<String>, Option<Nai
     };
 
     let date = if parts.len() > 1 {
-        match parse_date(parts[1]) {
-            Ok(parsed_date) => Some(parsed_date),
-            Err(_) => None, // 예상하지 못한 형식일 경우 None
-        }
+        // 예상하지 못한 형식일 경우 None
+        parse_date(parts[1]).ok()
     } else {
         None
     };
@@ -799,111 +402,68 @@ fn parse_payment_method(payment_str: &str) -> Result<(Option<String>, Option<Nai
     Ok((method, date))
 }
 
-// get text from locator
-async fn get_text_by_locator(client: &Client, locator: Locator<'_>) -> Option<String> {
-    match client.find(locator).await.ok() {
-        Some(element) => element.text().await.ok(),
-        None => None,
+// get text from selector. 없는 항목은 None
+async fn get_text_by_selector(client: &Client, selector: &Selector) -> Option<String> {
+    for spec in &selector.alternatives {
+        if let Ok(element) = client.find(spec.locator()).await {
+            return element.text().await.ok();
+        }
     }
+    None
 }
 
-// get text from locator art index
-async fn get_text_by_locator_at_index(
+// get text from selector at index
+async fn get_text_by_selector_at_index(
     client: &Client,
-    locator: Locator<'_>,
+    selector: &Selector,
     index: usize,
 ) -> Option<String> {
-    match client.find_all(locator).await.ok() {
-        Some(elements) => {
-            if let Some(element) = elements.get(index) {
-                return element.text().await.ok();
-            }
-            None
+    for spec in &selector.alternatives {
+        if let Ok(elements) = client.find_all(spec.locator()).await
+            && let Some(element) = elements.get(index)
+        {
+            return element.text().await.ok();
         }
-        None => None,
     }
+    None
 }
 
 // get_and_parsing_data year
 async fn extract_data_year(client: &Client, parent_id: &str) -> Result<KepcoData> {
-    let claim_date_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_payYm')]",
-            parent_id
-        )),
-    )
-    .await;
+    let claim_date_row =
+        get_text_by_selector(client, &selector(CLAIM_MONTH)?.fill("parent", parent_id)).await;
 
-    let date_range_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_gigan')]",
-            parent_id
-        )),
-    )
-    .await;
+    let date_range_row =
+        get_text_by_selector(client, &selector(DATE_RANGE)?.fill("parent", parent_id)).await;
 
-    let usage_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_useKwh')]",
-            parent_id
-        )),
-    )
-    .await;
+    let usage_row = get_text_by_selector(client, &selector(USAGE)?.fill("parent", parent_id)).await;
 
-    let amount_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_monthPay')]",
-            parent_id
-        )),
-    )
-    .await;
+    let amount_row =
+        get_text_by_selector(client, &selector(AMOUNT)?.fill("parent", parent_id)).await;
 
-    let paid_row = get_text_by_locator_at_index(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_pay')]",
-            parent_id
-        )),
-        1,
-    )
-    .await;
+    let paid_row =
+        get_text_by_selector_at_index(client, &selector(PAID)?.fill("parent", parent_id), 1).await;
 
-    let unpaid_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_payAmt')]",
-            parent_id
-        )),
-    )
-    .await;
+    let unpaid_row =
+        get_text_by_selector(client, &selector(UNPAID)?.fill("parent", parent_id)).await;
 
-    let payment_option_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_payGubnNDay')]",
-            parent_id
-        )),
-    )
-    .await;
+    let payment_option_row =
+        get_text_by_selector(client, &selector(PAYMENT_OPTION)?.fill("parent", parent_id)).await;
 
-    let claim_date = claim_date_row.map(|date| parse_date(&date)).transpose()?;
+    let claim_date = parse_date(&required_cell(claim_date_row, "claim month", parent_id)?)?;
     let (start_date, end_date) = date_range_row
         .map(|range| parse_date_range(&range))
         .transpose()?
-        .unwrap_or((None, None));
-    let usage = usage_row.map_or(Ok(0.0), |kwh| parse_use_kwh(&kwh))?;
-    let amount = amount_row.map_or(Ok(0), |amount| parse_amount(&amount))?;
-    let paid = paid_row.map_or(Ok(0), |paid| parse_amount(&paid))?;
-    let unpaid = unpaid_row.map_or(Ok(0), |unpaid| parse_amount(&unpaid))?;
+        .map_or((None, None), |(start, end)| (Some(start), Some(end)));
+    let usage = parse_use_kwh(&required_cell(usage_row, "usage", parent_id)?)?;
+    let amount = parse_amount(&required_cell(amount_row, "amount", parent_id)?)?;
+    let paid = parse_amount(&required_cell(paid_row, "paid", parent_id)?)?;
+    let unpaid = parse_amount(&required_cell(unpaid_row, "unpaid", parent_id)?)?;
     let (payment_method, payment_date) =
         payment_option_row.map_or(Ok((None, None)), |s| parse_payment_method(&s))?;
 
     Ok(KepcoData {
-        claim_date,
+        claim_date: Some(claim_date),
         start_date,
         end_date,
         usage,
@@ -917,27 +477,27 @@ async fn extract_data_year(client: &Client, parent_id: &str) -> Result<KepcoData
 
 // parse_data_from_parent_ids
 async fn parse_data_from_parent_ids(
-    client: &Arc<Client>,
+    client: &Client,
     map: Arc<DashMap<String, ()>>,
 ) -> Result<Vec<KepcoData>> {
     let mut tasks = vec![];
 
     for entry in map.iter() {
         let id = entry.key().clone();
-        let client = Arc::clone(client);
+        let client = client.clone();
         let task = tokio::spawn(async move { extract_data_year(&client, &id).await });
         tasks.push(task);
     }
 
     let results = futures::future::join_all(tasks).await;
 
-    let mut data_vec = Vec::new();
+    // 한 행이라도 읽지 못하면 일부만 반환하지 않고 실패
+    let mut data_vec = Vec::with_capacity(results.len());
     for result in results {
-        match result {
-            Ok(Ok(data)) => data_vec.push(data),
-            Ok(Err(e)) => eprintln!("Failed to extract data: {}", e),
-            Err(e) => eprintln!("Task failed: {}", e),
-        }
+        let data = result
+            .context("Failed to join row parsing task")?
+            .inspect_err(|e| eprintln!("Failed to extract data: {:?}", e))?;
+        data_vec.push(data);
     }
 
     Ok(data_vec)
@@ -945,69 +505,31 @@ async fn parse_data_from_parent_ids(
 
 // get_and_parsing_data monthly
 async fn extract_data_month(client: &Client, parent_id: &str) -> Result<KepcoData> {
-    let claim_date_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_payYm')]",
-            parent_id
-        )),
-    )
-    .await;
+    let claim_date_row =
+        get_text_by_selector(client, &selector(CLAIM_MONTH)?.fill("parent", parent_id)).await;
 
-    let usage_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_useKwh')]",
-            parent_id
-        )),
-    )
-    .await;
+    let usage_row = get_text_by_selector(client, &selector(USAGE)?.fill("parent", parent_id)).await;
 
-    let amount_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_monthPay')]",
-            parent_id
-        )),
-    )
-    .await;
+    let amount_row =
+        get_text_by_selector(client, &selector(AMOUNT)?.fill("parent", parent_id)).await;
 
-    let paid_row = get_text_by_locator_at_index(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_pay')]",
-            parent_id
-        )),
-        1,
-    )
-    .await;
+    let paid_row =
+        get_text_by_selector_at_index(client, &selector(PAID)?.fill("parent", parent_id), 1).await;
 
-    let unpaid_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_payAmt')]",
-            parent_id
-        )),
-    )
-    .await;
+    let unpaid_row =
+        get_text_by_selector(client, &selector(UNPAID)?.fill("parent", parent_id)).await;
 
-    let payment_method = get_text_by_locator(
-        client,
-        Locator::XPath(&format!(
-            "//*[@id='{}']//span[contains(@id, '_txt_payGubn')]",
-            parent_id
-        )),
-    )
-    .await;
+    let payment_method =
+        get_text_by_selector(client, &selector(PAYMENT_METHOD)?.fill("parent", parent_id)).await;
 
-    let claim_date = claim_date_row.map(|date| parse_date(&date)).transpose()?;
-    let usage = usage_row.map_or(Ok(0.0), |kwh| parse_use_kwh(&kwh))?;
-    let amount = amount_row.map_or(Ok(0), |amount| parse_amount(&amount))?;
-    let paid = paid_row.map_or(Ok(0), |paid| parse_amount(&paid))?;
-    let unpaid = unpaid_row.map_or(Ok(0), |unpaid| parse_amount(&unpaid))?;
+    let claim_date = parse_date(&required_cell(claim_date_row, "claim month", parent_id)?)?;
+    let usage = parse_use_kwh(&required_cell(usage_row, "usage", parent_id)?)?;
+    let amount = parse_amount(&required_cell(amount_row, "amount", parent_id)?)?;
+    let paid = parse_amount(&required_cell(paid_row, "paid", parent_id)?)?;
+    let unpaid = parse_amount(&required_cell(unpaid_row, "unpaid", parent_id)?)?;
 
     Ok(KepcoData {
-        claim_date,
+        claim_date: Some(claim_date),
         start_date: None,
         end_date: None,
         usage,
@@ -1019,66 +541,111 @@ async fn extract_data_month(client: &Client, parent_id: &str) -> Result<KepcoDat
     })
 }
 
+// 옵션 선택 후 로딩 대기. 청구 내역이 없는 월은 로딩 대신 또는 로딩 후 alert 로 안내되며 그 문구를 반환
+async fn kepco_select_month(client: &Client, option: &Element) -> Result<Option<String>> {
+    option.click().await.context("Failed to select option")?;
+    if let Some(message) = handle_dialog(client, DialogAction::Accept).await? {
+        return Ok(Some(message));
+    }
+
+    kepco_wait_processing(client).await?;
+    handle_dialog(client, DialogAction::Accept).await
+}
+
 // options 들의 결과값 parsing
-async fn parsing_options_data(
-    client: &Arc<Client>,
+pub async fn parsing_options_data(
+    client: &Client,
     select_locator: Locator<'_>,
     user_number: &str,
     option_index: &usize,
-    chromedriver_process: &mut Child,
+    progress: &ProgressReporter,
 ) -> Result<Vec<KepcoData>> {
-    let mut kepco_data_vec: Vec<KepcoData> = Vec::new();
-
     // option 요소
-    let options = client
-        .find(select_locator)
-        .await
-        .context("Failed to find select element")?
+    let options = find_element(client, select_locator)
+        .await?
         .find_all(Locator::Css("option"))
         .await
         .context("Failed to find options")?;
 
-    // option_index to last index data parsing
-    for i in *option_index..options.len() {
-        // 옵션 선택
-        options[i]
-            .click()
-            .await
-            .context("Failed to select option")?;
+    let mut kepco_data_vec: Vec<KepcoData> = Vec::with_capacity(options.len());
+    let customer_number_input = selector(CUSTOMER_NUMBER_INPUT)?;
+    let search_button = selector(SEARCH_BUTTON)?;
+    let data_box = selector(DATA_BOX)?;
 
-        // 로딩 대기
-        wait_for_element_hidden(
-            client,
-            Locator::Id("mf_wq_uuid_1_wq_processMsgComp"),
-            chromedriver_process,
-            Duration::from_secs(20),
-        )
-        .await?;
-
-        // 고객 번호 입력
+    // option_index to last index data parsing
+    let total = options.len().saturating_sub(*option_index);
+    for (i, option) in options.iter().skip(*option_index).enumerate() {
+        let label = option.text().await.unwrap_or_default();
+
+        // 청구 내역이 없는 월은 건너뜀
+        if let Some(message) = kepco_select_month(client, option).await? {
+            println!("No claim data for {}: {}", label, message);
+            progress.report(ProgressEvent::PeriodParsed {
+                label,
+                current: i + 1,
+                total,
+                rows: 0,
+            });
+            continue;
+        }
+
+        // 고객 번호 입력 후 검색
         enter_value_in_element(
             client,
-            Locator::Id("mf_wfm_layout_inp_searchCustNo"),
+            find_selector(client, &customer_number_input).await?,
             user_number,
         )
-        .await
-        .context("Failed to enter customer number")?;
-
-        // 검색 버튼 클릭
-        click_element(client, Locator::Id("mf_wfm_layout_btn_search")).await?;
-
-        // data box 로드 대기
-        wait_for_element(
-            client,
-            Locator::Id("mf_wfm_layout_ui_generator"),
-            chromedriver_process,
-        )
         .await?;
-
-        // data parsing
-        let data = extract_data_month(client, "mf_wfm_layout_ui_generator").await?;
+        click_element(client, find_selector(client, &search_button).await?).await?;
+        ensure_no_dialog(client, DialogAction::Accept).await?;
+
+        // data box 로드 대기 후 parsing
+        let data_box_locator = wait_selector(client, &data_box).await?;
+        let data_box_id = find_element(client, data_box_locator)
+            .await?
+            .attr("id")
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        let data = extract_data_month(client, &data_box_id).await?;
         kepco_data_vec.push(data);
+        progress.report(ProgressEvent::PeriodParsed {
+            label,
+            current: i + 1,
+            total,
+            rows: 1,
+        });
     }
 
     Ok(kepco_data_vec)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_parse_failure(error: &anyhow::Error) -> bool {
+        matches!(
+            error.downcast_ref::<CrawlError>(),
+            Some(CrawlError::ParseFailure(_))
+        )
+    }
+
+    #[test]
+    fn date_range_needs_two_valid_dates() {
+        assert_eq!(
+            parse_date_range("2024.01.01-2024.01.31").unwrap(),
+            (
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()
+            )
+        );
+        assert!(is_parse_failure(
+            &parse_date_range("2024.01.01").unwrap_err()
+        ));
+        assert!(is_parse_failure(
+            &parse_date_range("청구기간-조회중").unwrap_err()
+        ));
+    }
+}
@@ -1,846 +1,220 @@
 use anyhow::{Context, Result};
-use axum::{Json, http::StatusCode, response::IntoResponse};
+use axum::{Json, extract::State, response::IntoResponse};
 use chrono::NaiveDate;
 use dashmap::DashMap;
-use fantoccini::{Client, ClientBuilder, Locator, elements::Element};
-use serde_json::{Map, Value, json};
-use std::{
-    collections::HashSet,
-    process::{Child, Command},
-    sync::Arc,
-};
-use tokio::time::{Duration, timeout};
-
-use crate::models::handler::{
-    legacy_kepco::pp_models::{
-        MetaResponseData, PpAllPeriodsPaidData, PpAllPeriodsPaidDataResponse,
+use fantoccini::{Client, Locator, elements::Element};
+use std::{collections::HashSet, sync::Arc};
+use tokio::time::Duration;
+
+use crate::{
+    handlers::pp::commons::{pp_login, pp_wait_loading},
+    models::{
+        driver::selectors::{Selector, SelectorKey},
+        error::{crawl_errors::CrawlError, response_errors_def::ErrorResponseCode},
+        handler::{
+            jobs::progress::ProgressEvent,
+            legacy_kepco::pp_models::PpAllPeriodsPaidData,
+            pp::commons::{PP_SITE, PpRequestBody},
+        },
+        response::commons::basic_response,
     },
-    pp::commons::PpRequestBody,
+    server_init::app_state::AppState,
+    utils::{drivers::*, progress::ProgressReporter, selectors::selector, sites::site_url},
 };
 
+pub const CLAIM_GRID: SelectorKey = SelectorKey::new("pp", "monthly_claim", "grid");
+pub const YEAR_SELECT: SelectorKey = SelectorKey::new("pp", "monthly_claim", "year_select");
+pub const SEARCH_BUTTON: SelectorKey = SelectorKey::new("pp", "monthly_claim", "search_button");
+pub const ROW_CLAIM_DATE: SelectorKey = SelectorKey::new("pp", "monthly_claim", "row_claim_date");
+pub const ROW_USAGE: SelectorKey = SelectorKey::new("pp", "monthly_claim", "row_usage");
+const ROW_PAID: SelectorKey = SelectorKey::new("pp", "monthly_claim", "row_paid");
+const MONTHLY_CLAIM_LINK: SelectorKey = SelectorKey::new("pp", "common", "monthly_claim_link");
+pub const LATEST_PAID_DATA_COUNT: usize = 3;
+
 // 파워 플레너 모든기간 요금 조회 고객번호 기준
 pub async fn get_pp_all_periods_paid_data_handler(
+    State(state): State<AppState>,
     Json(params): Json<PpRequestBody>,
-) -> impl IntoResponse {
-    let url = "http://localhost:4445";
-    let target_url = "https://pp.kepco.co.kr";
-    let user_id = &params.userId;
-    let user_pw = &params.userPw;
-    let user_number = &params.userNum;
-
-    // driver path
-    let chromedriver_path = "/opt/homebrew/bin/chromedriver";
-    // let chromedriver_path = "src/driver/chromedriver";
-    // let chrome_binary_path = "/usr/bin/google-chrome";
-
-    // driver 실행
-    let mut chromedriver_process = match Command::new(chromedriver_path)
-        .arg("--port=4445")
-        // .arg(format!("--binary={}", chrome_binary_path))
-        .spawn()
-    {
-        Ok(process) => process,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Could not chromedriver_process!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
 
-    // driver 대기
-    tokio::time::sleep(Duration::from_secs(2)).await;
+    let client = state
+        .pool
+        .checkout(PpRequestBody::test_state(&params))
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
 
-    // headless, disable-gpu option
-    let capabilities: Map<String, Value> = match serde_json::from_value(json!({
-        "goog:chromeOptions": {
-            // "binary": "/usr/bin/google-chrome",
-            "args": ["--headless", "--disable-gpu"]
-        }
-    })) {
-        Ok(result) => result,
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Could not acquire connection from pool!",
-            )
-                .into_response();
-        }
-    };
+    let data_vec = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_pp_all_periods_paid_data(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
 
-    let client = loop {
-        match ClientBuilder::native()
-            .capabilities(capabilities.clone())
-            .connect(url)
-            .await
-        {
-            Ok(client) => break client,
-            Err(e) => {
-                eprintln!("Retrying to connect to WebDriver: {}", e);
-                tokio::time::sleep(Duration::from_millis(300)).await;
-            }
-        }
-    };
-
-    let client_arc = Arc::new(client.clone());
-
-    // view size
-    match client_arc.set_window_rect(0, 0, 774, 857).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to set view size!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 페이지 이동
-    match client_arc.goto(&format!("{}/intro.do", target_url)).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to navigate goto!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    Ok(basic_response(data_vec, start.elapsed()))
+}
 
-    // 공지 팝업 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("notice_auto_popup"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    //공지 팝업 비활성화
-    match click_element(
-        &client_arc,
-        Locator::XPath("/html/body/div[2]/div[3]/label"),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(_) => {}
-    };
-
-    // id 입력 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("RSA_USER_ID"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to wait_for_element!",
-            )
-                .into_response();
-        }
-    };
-    // id 입력
-    match enter_value_in_element(&client_arc, Locator::Id("RSA_USER_ID"), user_id).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to enter_value_in_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // pw 입력
-    match enter_value_in_element(&client_arc, Locator::Id("RSA_USER_PWD"), user_pw).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to enter_value_in_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 로그인 버튼 클릭
-    match click_element(
-        &client_arc,
-        Locator::XPath("/html/body/div[1]/div[2]/div[1]/form/fieldset/input[1]"),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+// 파워 플레너 최근 3개월 요금 조회 고객번호 기준
+pub async fn get_latest_3_pp_paid_data_handler(
+    State(state): State<AppState>,
+    Json(params): Json<PpRequestBody>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
 
-    // 로딩 대기
-    match wait_for_element_display_none(
-        &client_arc,
-        Locator::Id("backgroundLayer"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_display_none!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    let client = state
+        .pool
+        .checkout(PpRequestBody::test_state(&params))
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
 
-    // user_num selector 클릭
-    match click_element(
-        &client_arc,
-        Locator::XPath("/html/body/div[1]/div[1]/div/div/a[2]"),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // user_num 클릭
-    match click_element(
-        &client_arc,
-        Locator::XPath(
-            format!(
-                "/html/body/div[1]/div[1]/div/div/ul/li[1]/a[text()='{}']",
-                user_number,
-            )
-            .as_str(),
-        ),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    let data_vec = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_pp_latest_paid_data(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
 
-    // 로딩 대기
-    match wait_for_element_display_none(
-        &client_arc,
-        Locator::Id("backgroundLayer"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_display_none!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    Ok(basic_response(data_vec, start.elapsed()))
+}
 
-    // get 월별 청구 요금 url
-    let monthly_claim_href = get_href_by_locator(
-        &client_arc,
-        Locator::XPath("/html/body/div[1]/div[2]/div[1]/ul[4]/li[5]/a"),
-    )
-    .await
-    .unwrap_or_else(|| "".to_string());
-
-    let claim_url = format!("{}{}", target_url, monthly_claim_href);
-
-    // 월별 청구 요금 이동
-    match client_arc.goto(&claim_url).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed go to monthly_claim_href: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+pub async fn crawl_pp_all_periods_paid_data(
+    client: &Client,
+    params: PpRequestBody,
+    progress: &ProgressReporter,
+) -> Result<Vec<PpAllPeriodsPaidData>, ErrorResponseCode> {
+    pp_login(client, params, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
 
-    // 로딩 대기
-    match wait_for_element_display_none(
-        &client_arc,
-        Locator::Id("backgroundLayer"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_display_none!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    pp_all_periods_paid_data(client, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_PAID_DATA.caused_by(e))
+}
 
-    // data from table -> vec
-    let mut data_vec = match parse_data_from_table(&client_arc, "//*[@id='grid']/tbody").await {
-        Ok(vec) => vec,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Could not Parse data_from_table!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+pub async fn crawl_pp_latest_paid_data(
+    client: &Client,
+    params: PpRequestBody,
+    progress: &ProgressReporter,
+) -> Result<Vec<PpAllPeriodsPaidData>, ErrorResponseCode> {
+    pp_login(client, params, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
 
-    // select locator
-    let select_locator = Locator::Id("year");
+    pp_latest_paid_data(client, LATEST_PAID_DATA_COUNT, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_PAID_DATA.caused_by(e))
+}
+
+pub async fn pp_all_periods_paid_data(
+    client: &Client,
+    progress: &ProgressReporter,
+) -> Result<Vec<PpAllPeriodsPaidData>> {
+    go_to_monthly_claim(client).await?;
+
+    // data from table -> vec
+    let mut data_vec = parse_data_from_table(client, &selector(CLAIM_GRID)?).await?;
+    progress.report(ProgressEvent::RowsFound {
+        rows: data_vec.len(),
+    });
 
     // 1year over data parsing
-    let mut additional_data_vec = match parsing_options_data(
-        &client_arc,
-        select_locator,
+    let year_select = selector(YEAR_SELECT)?;
+    let mut additional_data_vec = parsing_options_data(
+        client,
+        find_selector(client, &year_select).await?,
         &1,
-        &mut chromedriver_process,
+        progress,
     )
-    .await
-    {
-        Ok(vec) => vec,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed parsing_options_data!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    .await?;
 
     // data 병합
     data_vec.append(&mut additional_data_vec);
+    dedup_and_sort_by_claim_date(&mut data_vec);
 
-    // 중복 제거
-    let mut unique_dates = HashSet::new();
-    data_vec.retain(|entry| unique_dates.insert(entry.claim_date));
-
-    // 정렬
-    data_vec.sort_by(|a, b| b.claim_date.cmp(&a.claim_date));
-
-    let response: PpAllPeriodsPaidDataResponse = PpAllPeriodsPaidDataResponse {
-        data: data_vec,
-        meta: MetaResponseData {},
-    };
-
-    match client.delete_all_cookies().await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed delete_all_cookies!: {:?}", e),
-            )
-                .into_response();
-        }
-    }
-
-    // ChromeDriver 프로세스 종료
-    match chromedriver_process.kill() {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed chromedriver_process.kill!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    (StatusCode::OK, Json(response)).into_response()
+    println!("pp_all_periods_paid_data successfully");
+    Ok(data_vec)
 }
 
-// latest 3 data
-pub async fn get_latest_3_pp_paid_data_handler(
-    Json(params): Json<PpRequestBody>,
-) -> impl IntoResponse {
-    let url = "http://localhost:4450";
-    let target_url = "https://pp.kepco.co.kr";
-    let user_id = &params.userId;
-    let user_pw = &params.userPw;
-    let user_number = &params.userNum;
-
-    // driver path
-    let chromedriver_path = "/opt/homebrew/bin/chromedriver";
-    // let chromedriver_path = "src/driver/chromedriver";
-    // let chrome_binary_path = "/usr/bin/google-chrome";
-
-    // driver 실행
-    let mut chromedriver_process = match Command::new(chromedriver_path)
-        .arg("--port=4450")
-        // .arg(format!("--binary={}", chrome_binary_path))
-        .spawn()
-    {
-        Ok(process) => process,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Could not chromedriver_process!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    // driver 대기
-    tokio::time::sleep(Duration::from_secs(2)).await;
-
-    // headless, disable-gpu option
-    let capabilities: Map<String, Value> = match serde_json::from_value(json!({
-        "goog:chromeOptions": {
-            // "binary": "/usr/bin/google-chrome",
-            "args": ["--headless", "--disable-gpu"]
-        }
-    })) {
-        Ok(result) => result,
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Could not acquire connection from pool!",
-            )
-                .into_response();
-        }
-    };
-
-    let client = loop {
-        match ClientBuilder::native()
-            .capabilities(capabilities.clone())
-            .connect(url)
+// 최신 연도부터 필요한 개수가 채워질 때까지만 조회
+pub async fn pp_latest_paid_data(
+    client: &Client,
+    count: usize,
+    progress: &ProgressReporter,
+) -> Result<Vec<PpAllPeriodsPaidData>> {
+    go_to_monthly_claim(client).await?;
+
+    let mut data_vec = parse_data_from_table(client, &selector(CLAIM_GRID)?).await?;
+    dedup_and_sort_by_claim_date(&mut data_vec);
+    progress.report(ProgressEvent::RowsFound {
+        rows: data_vec.len(),
+    });
+
+    if data_vec.len() < count {
+        let year_select = selector(YEAR_SELECT)?;
+        let options = find_element(client, find_selector(client, &year_select).await?)
+            .await?
+            .find_all(Locator::Css("option"))
             .await
-        {
-            Ok(client) => break client,
-            Err(e) => {
-                eprintln!("Retrying to connect to WebDriver: {}", e);
-                tokio::time::sleep(Duration::from_millis(300)).await;
-            }
-        }
-    };
-
-    let client_arc = Arc::new(client.clone());
-
-    // view size
-    match client_arc.set_window_rect(0, 0, 774, 857).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to set view size!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 페이지 이동
-    match client_arc.goto(&format!("{}/intro.do", target_url)).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to navigate goto!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    // 공지 팝업 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("notice_auto_popup"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    //공지 팝업 비활성화
-    match click_element(
-        &client_arc,
-        Locator::XPath("/html/body/div[2]/div[3]/label"),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(_) => {}
-    };
-
-    // id 입력 로드 대기
-    match wait_for_element(
-        &client_arc,
-        Locator::Id("RSA_USER_ID"),
-        &mut chromedriver_process,
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(_) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Failed to wait_for_element!",
-            )
-                .into_response();
-        }
-    };
-    // id 입력
-    match enter_value_in_element(&client_arc, Locator::Id("RSA_USER_ID"), user_id).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to enter_value_in_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // pw 입력
-    match enter_value_in_element(&client_arc, Locator::Id("RSA_USER_PWD"), user_pw).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to enter_value_in_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // 로그인 버튼 클릭
-    match click_element(
-        &client_arc,
-        Locator::XPath("/html/body/div[1]/div[2]/div[1]/form/fieldset/input[1]"),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+            .context("Failed to find options")?;
 
-    // 로딩 대기
-    match wait_for_element_display_none(
-        &client_arc,
-        Locator::Id("backgroundLayer"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_display_none!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+        // 연초에는 올해 청구분이 부족하므로 이전 연도로 넘어감
+        let total = options.len().saturating_sub(1);
+        for (i, option) in options.iter().skip(1).enumerate() {
+            let mut data = parse_year_option(client, option, i + 1, total, progress).await?;
+            data_vec.append(&mut data);
+            dedup_and_sort_by_claim_date(&mut data_vec);
 
-    // user_num selector 클릭
-    match click_element(
-        &client_arc,
-        Locator::XPath("/html/body/div[1]/div[1]/div/div/a[2]"),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-    // user_num 클릭
-    match click_element(
-        &client_arc,
-        Locator::XPath(
-            format!(
-                "/html/body/div[1]/div[1]/div/div/ul/li[1]/a[text()='{}']",
-                user_number,
-            )
-            .as_str(),
-        ),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to click_element!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    // 로딩 대기
-    match wait_for_element_display_none(
-        &client_arc,
-        Locator::Id("backgroundLayer"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_display_none!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    // get 월별 청구 요금 url
-    let monthly_claim_href = get_href_by_locator(
-        &client_arc,
-        Locator::XPath("/html/body/div[1]/div[2]/div[1]/ul[4]/li[5]/a"),
-    )
-    .await
-    .unwrap_or_else(|| "".to_string());
-
-    let claim_url = format!("{}{}", target_url, monthly_claim_href);
-
-    // 월별 청구 요금 이동
-    match client_arc.goto(&claim_url).await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed go to monthly_claim_href: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    // 로딩 대기
-    match wait_for_element_display_none(
-        &client_arc,
-        Locator::Id("backgroundLayer"),
-        &mut chromedriver_process,
-        Duration::from_secs(20),
-    )
-    .await
-    {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to wait_for_element_display_none!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    todo!();
-    // parse_data_from_table -> get latest 3 data
-
-    // data from table -> vec
-    let mut data_vec = match parse_data_from_table(&client_arc, "//*[@id='grid']/tbody").await {
-        Ok(vec) => vec,
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Could not Parse data_from_table!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
-
-    // 정렬
-    data_vec.sort_by(|a, b| b.claim_date.cmp(&a.claim_date));
-
-    let response: PpAllPeriodsPaidDataResponse = PpAllPeriodsPaidDataResponse {
-        data: data_vec,
-        meta: MetaResponseData {},
-    };
-
-    match client.delete_all_cookies().await {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed delete_all_cookies!: {:?}", e),
-            )
-                .into_response();
+            if data_vec.len() >= count {
+                break;
+            }
         }
     }
 
-    // ChromeDriver 프로세스 종료
-    match chromedriver_process.kill() {
-        Ok(_) => {}
-        Err(e) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed chromedriver_process.kill!: {:?}", e),
-            )
-                .into_response();
-        }
-    };
+    data_vec.truncate(count);
 
-    (StatusCode::OK, Json(response)).into_response()
+    println!("pp_latest_paid_data successfully");
+    Ok(data_vec)
 }
 
-// 요소 대기
-async fn wait_for_element(
-    client: &Client,
-    locator: Locator<'_>,
-    chromedriver_process: &mut Child,
-) -> Result<Option<Element>> {
-    match client.wait().for_element(locator).await {
-        Ok(element) => Ok(Some(element)),
-        Err(e) => {
-            eprintln!("Failed to find the element: {:?}\n {}", locator, e);
-            client
-                .clone()
-                .close()
-                .await
-                .context("Failed to close client")?;
-            chromedriver_process
-                .kill()
-                .expect("failed to kill ChromeDriver");
-            Err(anyhow::anyhow!("Failed to find the element: {:?}", e))
-        }
-    }
-}
+// 중복 제거 후 최신순 정렬
+fn dedup_and_sort_by_claim_date(data_vec: &mut Vec<PpAllPeriodsPaidData>) {
+    let mut unique_dates = HashSet::new();
+    data_vec.retain(|entry| unique_dates.insert(entry.claim_date));
 
-// 요소 클릭
-async fn click_element(client: &Client, locator: Locator<'_>) -> Result<()> {
-    if let Ok(element) = client.find(locator).await {
-        element
-            .click()
-            .await
-            .context(format!("Failed to click the element: {:?}", locator))?;
-        println!("Element clicked successfully: {:?}", locator);
-    } else {
-        eprintln!("Failed to find the element: {:?}", locator);
-        return Err(anyhow::anyhow!("Failed to find the element: {:?}", locator));
-    }
-    Ok(())
+    data_vec.sort_by_key(|entry| std::cmp::Reverse(entry.claim_date));
 }
 
-// 요소에 값 입력
-async fn enter_value_in_element(client: &Client, locator: Locator<'_>, text: &str) -> Result<()> {
-    if let Ok(element) = client.find(locator).await {
-        if let Err(e) = element.send_keys(text).await {
-            eprintln!("Failed to enter text: {}", e);
-        } else {
-            println!("Text entered successfully: {:?}", locator);
-        }
-    } else {
-        eprintln!("Failed to find the input element: {:?}", locator);
-    }
-    Ok(())
-}
+// 월별 청구 요금 이동
+pub async fn go_to_monthly_claim(client: &Client) -> Result<()> {
+    // get 월별 청구 요금 url
+    let monthly_claim_link = selector(MONTHLY_CLAIM_LINK)?;
+    let monthly_claim_href = get_href_by_selector(client, &monthly_claim_link).await?;
 
-// 요소 비활성화 대기
-async fn wait_for_element_display_none(
-    client: &Client,
-    locator: Locator<'_>,
-    chromedriver_process: &mut Child,
-    duration: Duration,
-) -> Result<()> {
-    let element = match wait_for_element(client, locator, chromedriver_process).await? {
-        Some(element) => element,
-        None => return Err(anyhow::anyhow!("Failed to find the element: {:?}", locator)),
-    };
-
-    let element_hidden = timeout(duration, async {
-        loop {
-            match element.attr("style").await {
-                Ok(Some(style)) if style.contains("display: none") => {
-                    println!("Element is hidden (style=\"display: none\")");
-                    break;
-                }
-                Ok(_) => {
-                    eprintln!("Element is not hidden, retrying...");
-                }
-                Err(e) => {
-                    eprintln!("Failed to get style attribute: {}", e);
-                }
-            }
-            tokio::time::sleep(Duration::from_millis(500)).await;
-        }
-    })
-    .await;
-
-    if element_hidden.is_err() {
-        Err(anyhow::anyhow!(
-            "Failed to find the element within the given duration"
-        ))
-    } else {
-        Ok(())
-    }
+    go_to_url(client, &site_url(PP_SITE, &monthly_claim_href)?).await?;
+
+    // 로딩 대기
+    pp_wait_loading(client, Duration::from_secs(20)).await
 }
 
 // 자식 요소들의 ID -> DashMap
-async fn get_children_ids_to_map(
+pub async fn get_children_ids_to_map(
     client: &Client,
-    parent_xpath: &str,
+    parent: &Selector,
 ) -> Result<Arc<DashMap<String, ()>>> {
-    let script = format!(
-        r#"
-        let parent = document.evaluate("{}", document, null, XPathResult.FIRST_ORDERED_NODE_TYPE, null).singleNodeValue;
-        if (parent === null) {{
-            throw new Error('Parent element not found');
-        }}
-        let children = parent.querySelectorAll('tr');
+    let parent = find_element(client, find_selector(client, parent).await?).await?;
+    let script = r#"
+        let children = arguments[0].querySelectorAll('tr');
         let ids = [];
-        for (let i = 0; i < children.length; i++) {{
+        for (let i = 0; i < children.length; i++) {
             ids.push(children[i].id);
-        }}
+        }
         return ids;
-        "#,
-        parent_xpath
-    );
+        "#;
 
     let result = client
-        .execute(&script, vec![])
+        .execute(script, vec![serde_json::to_value(&parent)?])
         .await
         .context("Failed to execute script to get children IDs")?;
 
@@ -861,68 +235,75 @@ async fn get_children_ids_to_map(
     Ok(map)
 }
 
-// get text from locator
-async fn get_text_by_locator(client: &Client, locator: Locator<'_>) -> Option<String> {
-    match client.find(locator).await.ok() {
-        Some(element) => element.text().await.ok(),
-        None => None,
+// get text from selector. 없는 셀은 None
+async fn get_text_by_selector(client: &Client, selector: &Selector) -> Option<String> {
+    for spec in &selector.alternatives {
+        if let Ok(element) = client.find(spec.locator()).await {
+            return element.text().await.ok();
+        }
     }
+    None
 }
 
-// get href from locator
-async fn get_href_by_locator(client: &Client, locator: Locator<'_>) -> Option<String> {
-    match client.find(locator).await.ok() {
-        Some(element) => element.attr("href").await.ok().flatten(),
-        None => None,
-    }
+// get href from selector. 링크가 없거나 href 가 비어 있으면 SelectorNotFound
+async fn get_href_by_selector(client: &Client, selector: &Selector) -> Result<String> {
+    let element = find_element(client, find_selector(client, selector).await?).await?;
+    let href = element
+        .attr("href")
+        .await
+        .with_context(|| format!("Failed to read href of {}", selector.key))?
+        .filter(|href| !href.trim().is_empty());
+
+    href.ok_or_else(|| {
+        eprintln!("Selector {} has no href", selector.key);
+        CrawlError::SelectorNotFound(format!("Selector {} has no href", selector.key)).into()
+    })
 }
 
 // parsing 청구 기간
-fn parse_date(date_str: &str) -> Result<NaiveDate> {
+pub fn parse_date(date_str: &str) -> Result<NaiveDate> {
     // 일자를 1로 설정
     let date_with_day = format!("{} 01일", date_str);
-    NaiveDate::parse_from_str(&date_with_day, "%Y년 %m월 %d일").context("Failed to parse date")
+    NaiveDate::parse_from_str(&date_with_day, "%Y년 %m월 %d일")
+        .with_context(|| CrawlError::ParseFailure(format!("date '{}'", date_str)))
 }
 
 // parsing 사용량
-fn parse_use_kwh(kwh_str: &str) -> Result<f64> {
+pub fn parse_use_kwh(kwh_str: &str) -> Result<f64> {
     let cleaned_str = kwh_str.replace(",", "").replace("kWh", "");
     cleaned_str
         .parse::<f64>()
-        .context("Failed to parse use kWh")
+        .with_context(|| CrawlError::ParseFailure(format!("use kWh '{}'", kwh_str)))
 }
 
 // parsing 요금
-fn parse_paid(amount_str: &str) -> Result<i64> {
+pub fn parse_paid(amount_str: &str) -> Result<i64> {
     let amount_part = amount_str.split('원').next().unwrap_or(amount_str);
 
-    let amount = amount_part.replace(",", "").replace(".", "");
-    amount.parse::<i64>().context("Failed to parse amount")
+    let amount = amount_part.replace([',', '.'], "");
+    amount
+        .parse::<i64>()
+        .with_context(|| CrawlError::ParseFailure(format!("amount '{}'", amount_str)))
+}
+
+// 행에서 찾지 못한 항목은 ParseFailure
+fn required_cell(cell: Option<String>, name: &str, row_id: &str) -> Result<String> {
+    cell.ok_or_else(|| CrawlError::ParseFailure(format!("{} of row '{}'", name, row_id)).into())
 }
 
 // get_and_parsing_data year
 async fn extract_data_year(client: &Client, parent_id: &str) -> Result<PpAllPeriodsPaidData> {
-    let claim_date_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!("//*[@id='{}']/td[1]/a/span", parent_id)),
-    )
-    .await;
-
-    let usage_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!("//*[@id='{}']/td[4]", parent_id)),
-    )
-    .await;
-
-    let paid_row = get_text_by_locator(
-        client,
-        Locator::XPath(&format!("//*[@id='{}']/td[8]", parent_id)),
-    )
-    .await;
-
-    let claim_date = claim_date_row.map_or(Ok(Default::default()), |date| parse_date(&date))?;
-    let usage = usage_row.map_or(Ok(0.0), |kwh| parse_use_kwh(&kwh))?;
-    let paid = paid_row.map_or(Ok(0), |paid| parse_paid(&paid))?;
+    let claim_date_row =
+        get_text_by_selector(client, &selector(ROW_CLAIM_DATE)?.fill("row", parent_id)).await;
+    let usage_row =
+        get_text_by_selector(client, &selector(ROW_USAGE)?.fill("row", parent_id)).await;
+    let paid_row = get_text_by_selector(client, &selector(ROW_PAID)?.fill("row", parent_id)).await;
+
+    // 청구 기간이 없으면 정렬 / 최신 3개월 선택이 틀어지므로 행 전체를 실패로 처리
+    let claim_date = parse_date(&required_cell(claim_date_row, "claim date", parent_id)?)?;
+    // 사용량 / 요금도 0 으로 채우지 않고 실패로 처리
+    let usage = parse_use_kwh(&required_cell(usage_row, "usage", parent_id)?)?;
+    let paid = parse_paid(&required_cell(paid_row, "paid", parent_id)?)?;
 
     Ok(PpAllPeriodsPaidData {
         claim_date,
@@ -933,29 +314,29 @@ async fn extract_data_year(client: &Client, parent_id: &str) -> Result<PpAllPeri
 
 // parse_data_from_parent_ids
 async fn parse_data_from_table(
-    client: &Arc<Client>,
-    parent_xpath: &str,
+    client: &Client,
+    parent: &Selector,
 ) -> Result<Vec<PpAllPeriodsPaidData>> {
     let mut tasks = vec![];
 
-    let map = get_children_ids_to_map(&client, parent_xpath).await?;
+    let map = get_children_ids_to_map(client, parent).await?;
 
     for entry in map.iter() {
         let id = entry.key().clone();
-        let client = Arc::clone(&client);
+        let client = client.clone();
         let task = tokio::spawn(async move { extract_data_year(&client, &id).await });
         tasks.push(task);
     }
 
     let results = futures::future::join_all(tasks).await;
 
-    let mut data_vec = Vec::new();
+    // 한 행이라도 읽지 못하면 일부만 반환하지 않고 실패
+    let mut data_vec = Vec::with_capacity(results.len());
     for result in results {
-        match result {
-            Ok(Ok(data)) => data_vec.push(data),
-            Ok(Err(e)) => eprintln!("Failed to extract data: {}", e),
-            Err(e) => eprintln!("Task failed: {}", e),
-        }
+        let data = result
+            .context("Failed to join row parsing task")?
+            .inspect_err(|e| eprintln!("Failed to extract data: {:?}", e))?;
+        data_vec.push(data);
     }
 
     Ok(data_vec)
@@ -963,10 +344,10 @@ async fn parse_data_from_table(
 
 // options 들의 결과값 parsing
 async fn parsing_options_data(
-    client: &Arc<Client>,
+    client: &Client,
     select_locator: Locator<'_>,
     option_index: &usize,
-    chromedriver_process: &mut Child,
+    progress: &ProgressReporter,
 ) -> Result<Vec<PpAllPeriodsPaidData>> {
     // option 요소
     let options = client
@@ -980,29 +361,43 @@ async fn parsing_options_data(
     let mut vec: Vec<PpAllPeriodsPaidData> = Vec::with_capacity(options.len() * 12);
 
     // option_index to last index data parsing
-    for i in *option_index..options.len() {
-        // 옵션 선택
-        options[i]
-            .click()
-            .await
-            .context("Failed to select option")?;
-
-        // 조회 버튼 클릭
-        click_element(&client, Locator::XPath("//*[@id='txt']/div[2]/p/span[1]/a")).await?;
-
-        // 로딩 대기
-        wait_for_element_display_none(
-            &client,
-            Locator::Id("backgroundLayer"),
-            chromedriver_process,
-            Duration::from_secs(10),
-        )
-        .await?;
-
-        // data parsing
-        let mut data = parse_data_from_table(&client, "//*[@id='grid']/tbody").await?;
+    let total = options.len().saturating_sub(*option_index);
+    for (i, option) in options.iter().skip(*option_index).enumerate() {
+        let mut data = parse_year_option(client, option, i + 1, total, progress).await?;
         vec.append(&mut data);
     }
 
     Ok(vec)
 }
+
+// 연도 option 선택 후 조회 결과 parsing
+async fn parse_year_option(
+    client: &Client,
+    option: &Element,
+    current: usize,
+    total: usize,
+    progress: &ProgressReporter,
+) -> Result<Vec<PpAllPeriodsPaidData>> {
+    let label = option.text().await.unwrap_or_default();
+
+    // 옵션 선택
+    option.click().await.context("Failed to select option")?;
+
+    // 조회 버튼 클릭
+    let search_button = selector(SEARCH_BUTTON)?;
+    click_element(client, find_selector(client, &search_button).await?).await?;
+
+    // 로딩 대기
+    pp_wait_loading(client, Duration::from_secs(10)).await?;
+
+    // data parsing
+    let data = parse_data_from_table(client, &selector(CLAIM_GRID)?).await?;
+    progress.report(ProgressEvent::PeriodParsed {
+        label,
+        current,
+        total,
+        rows: data.len(),
+    });
+
+    Ok(data)
+}
@@ -0,0 +1,198 @@
+use axum::{
+    Extension, Json,
+    extract::State,
+    response::{IntoResponse, Response},
+};
+use fantoccini::Client;
+use serde_json::Value;
+
+use crate::{
+    handlers::{
+        legacy_kepco::pp_kepco::{
+            LATEST_PAID_DATA_COUNT, pp_all_periods_paid_data, pp_latest_paid_data,
+        },
+        pp::{
+            commons::{pp_customer_numbers, pp_go_home, pp_select_customer, pp_sign_in},
+            user_info::pp_user_info,
+        },
+    },
+    models::{
+        error::response_errors_def::ErrorResponseCode,
+        handler::pp::batch::{CustomerNumbers, PpBatchKind, PpBatchRequestBody, PpBatchResult},
+        response::commons::{basic_response, to_json_value},
+    },
+    server_init::app_state::AppState,
+    utils::{
+        artifacts::ArtifactStore,
+        auth::{CrawlQuota, crawl_rejection},
+        progress::ProgressReporter,
+    },
+};
+
+pub async fn get_pp_batch_handler(
+    State(state): State<AppState>,
+    Extension(quota): Extension<CrawlQuota>,
+    Json(params): Json<PpBatchRequestBody>,
+) -> Result<impl IntoResponse, Response> {
+    let start = std::time::Instant::now();
+
+    charge_listed_customers(&quota, &params).map_err(crawl_rejection)?;
+
+    let client = state
+        .pool
+        .checkout(params.test_state())
+        .await
+        .map_err(|e| crawl_rejection(ErrorResponseCode::CHECKOUT_SESSION.caused_by(e)))?;
+
+    let results = run_pp_batch(
+        &client,
+        &state.artifacts,
+        params,
+        &quota,
+        &ProgressReporter::none(),
+    )
+    .await
+    .map_err(crawl_rejection)?;
+
+    Ok(basic_response(results, start.elapsed()))
+}
+
+// 일일 횟수는 고객번호마다 1회. 요청 1회는 acquire_crawl 에서 이미 차감됨
+fn charge_customers(quota: &CrawlQuota, customers: usize) -> Result<(), ErrorResponseCode> {
+    quota.charge((customers as u64).saturating_sub(1))
+}
+
+// 고객번호 목록이면 크롤링 전에 차감. "all" 은 목록을 조회한 뒤 run_pp_batch 에서 차감
+pub fn charge_listed_customers(
+    quota: &CrawlQuota,
+    params: &PpBatchRequestBody,
+) -> Result<(), ErrorResponseCode> {
+    match &params.userNums {
+        CustomerNumbers::List(user_nums) => charge_customers(quota, user_nums.len()),
+        CustomerNumbers::All(_) => Ok(()),
+    }
+}
+
+// 로그인 → "all" 차감 → 고객번호별 크롤링.
+// 일일 횟수 초과는 크롤링 실패가 아니므로 artifact 를 남기는 구간 밖에서 차감
+pub async fn run_pp_batch(
+    client: &Client,
+    artifacts: &ArtifactStore,
+    params: PpBatchRequestBody,
+    quota: &CrawlQuota,
+    progress: &ProgressReporter,
+) -> Result<Vec<PpBatchResult>, ErrorResponseCode> {
+    let available = artifacts
+        .capture_on_error(client, pp_batch_sign_in(client, &params, progress).await)
+        .await?;
+
+    if let CustomerNumbers::All(_) = params.userNums {
+        charge_customers(quota, available.len())?;
+    }
+
+    artifacts
+        .capture_on_error(
+            client,
+            crawl_pp_batch(client, params, available, progress).await,
+        )
+        .await
+}
+
+// 로그인 후 계정에 등록된 고객번호 목록
+async fn pp_batch_sign_in(
+    client: &Client,
+    params: &PpBatchRequestBody,
+    progress: &ProgressReporter,
+) -> Result<Vec<String>, ErrorResponseCode> {
+    pp_sign_in(client, &params.userId, &params.userPw, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+
+    pp_customer_numbers(client)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))
+}
+
+// 로그인된 세션에서 고객번호를 전환하면서 크롤링. 고객번호별 실패는 결과에만 기록
+async fn crawl_pp_batch(
+    client: &Client,
+    params: PpBatchRequestBody,
+    available: Vec<String>,
+    progress: &ProgressReporter,
+) -> Result<Vec<PpBatchResult>, ErrorResponseCode> {
+    let user_nums = match params.userNums {
+        CustomerNumbers::List(user_nums) => user_nums,
+        CustomerNumbers::All(_) => available.clone(),
+    };
+
+    let mut results = Vec::with_capacity(user_nums.len());
+    let mut first = true;
+
+    for user_num in user_nums {
+        if !available.contains(&user_num) {
+            results.push(PpBatchResult {
+                user_num,
+                data: None,
+                error: Some(ErrorResponseCode::PP_CUSTOMER_NOT_FOUND.to_json()),
+            });
+            continue;
+        }
+
+        let outcome =
+            crawl_selected_customer(client, &user_num, params.kind, first, progress).await;
+        first = false;
+
+        results.push(match outcome {
+            Ok(data) => PpBatchResult {
+                user_num,
+                data: Some(data),
+                error: None,
+            },
+            Err(e) => PpBatchResult {
+                user_num,
+                data: None,
+                error: Some(e.to_json()),
+            },
+        });
+    }
+
+    println!("crawl_pp_batch successfully");
+    Ok(results)
+}
+
+async fn crawl_selected_customer(
+    client: &Client,
+    user_num: &str,
+    kind: PpBatchKind,
+    first: bool,
+    progress: &ProgressReporter,
+) -> Result<Value, ErrorResponseCode> {
+    // 이전 고객번호의 하위 페이지에서는 고객번호 목록이 없을 수 있음
+    if !first {
+        pp_go_home(client)
+            .await
+            .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+    }
+
+    pp_select_customer(client, user_num, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+
+    match kind {
+        PpBatchKind::PpUserInfo => to_json_value(
+            pp_user_info(client)
+                .await
+                .map_err(|e| ErrorResponseCode::PP_USER_INFO.caused_by(e))?,
+        ),
+        PpBatchKind::PpAllPeriods => to_json_value(
+            pp_all_periods_paid_data(client, progress)
+                .await
+                .map_err(|e| ErrorResponseCode::PP_PAID_DATA.caused_by(e))?,
+        ),
+        PpBatchKind::PpLatest3 => to_json_value(
+            pp_latest_paid_data(client, LATEST_PAID_DATA_COUNT, progress)
+                .await
+                .map_err(|e| ErrorResponseCode::PP_PAID_DATA.caused_by(e))?,
+        ),
+    }
+}
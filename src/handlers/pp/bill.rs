@@ -0,0 +1,197 @@
+use anyhow::Result;
+use axum::{Json, extract::State, response::IntoResponse};
+use fantoccini::Client;
+use std::time::Duration;
+
+use crate::{
+    handlers::{
+        legacy_kepco::pp_kepco::{
+            CLAIM_GRID, ROW_CLAIM_DATE, ROW_USAGE, get_children_ids_to_map, go_to_monthly_claim,
+            parse_date, parse_paid, parse_use_kwh,
+        },
+        pp::commons::{pp_login, pp_wait_loading},
+    },
+    models::{
+        driver::selectors::SelectorKey,
+        error::{crawl_errors::CrawlError, response_errors_def::ErrorResponseCode},
+        handler::{
+            jobs::progress::ProgressEvent,
+            pp::{
+                bill::{BillBreakdown, BillItem, EnergyCharge},
+                commons::PpRequestBody,
+                load_profile::LoadBand,
+            },
+        },
+        response::commons::basic_response,
+    },
+    server_init::app_state::AppState,
+    utils::{drivers::*, progress::ProgressReporter, selectors::selector},
+};
+
+const ROW_DETAIL_LINK: SelectorKey = SelectorKey::new("pp", "monthly_claim", "row_detail_link");
+const BILL_DETAIL_LAYER: SelectorKey = SelectorKey::new("pp", "bill_detail", "layer");
+const BILL_DETAIL_CLOSE: SelectorKey = SelectorKey::new("pp", "bill_detail", "close_button");
+
+pub async fn get_pp_bill_breakdown_handler(
+    State(state): State<AppState>,
+    Json(params): Json<PpRequestBody>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let client = state
+        .pool
+        .checkout(params.test_state())
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+
+    let bills = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_pp_bill_breakdown(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
+
+    Ok(basic_response(bills, start.elapsed()))
+}
+
+pub async fn crawl_pp_bill_breakdown(
+    client: &Client,
+    params: PpRequestBody,
+    progress: &ProgressReporter,
+) -> Result<Vec<BillBreakdown>, ErrorResponseCode> {
+    pp_login(client, params, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+
+    pp_bill_breakdown(client, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_BILL_BREAKDOWN.caused_by(e))
+}
+
+// 월별 청구 요금의 각 행을 열어 상세 내역 parsing
+async fn pp_bill_breakdown(
+    client: &Client,
+    progress: &ProgressReporter,
+) -> Result<Vec<BillBreakdown>> {
+    go_to_monthly_claim(client).await?;
+
+    let mut row_ids = get_children_ids_to_map(client, &selector(CLAIM_GRID)?)
+        .await?
+        .iter()
+        .map(|entry| entry.key().clone())
+        .collect::<Vec<String>>();
+    row_ids.sort();
+    progress.report(ProgressEvent::RowsFound {
+        rows: row_ids.len(),
+    });
+
+    let total = row_ids.len();
+    let mut bills = Vec::with_capacity(total);
+
+    // 상세 레이어는 한 번에 하나만 열리므로 순차 처리
+    for (i, row_id) in row_ids.iter().enumerate() {
+        let bill = parse_bill_row(client, row_id).await?;
+        progress.report(ProgressEvent::PeriodParsed {
+            label: bill.claim_date.format("%Y-%m").to_string(),
+            current: i + 1,
+            total,
+            rows: 1,
+        });
+        bills.push(bill);
+    }
+
+    bills.sort_by_key(|bill| std::cmp::Reverse(bill.claim_date));
+
+    println!("pp_bill_breakdown successfully");
+    Ok(bills)
+}
+
+async fn parse_bill_row(client: &Client, row_id: &str) -> Result<BillBreakdown> {
+    let claim_date =
+        text_by_selector(client, &selector(ROW_CLAIM_DATE)?.fill("row", row_id)).await?;
+    let usage = text_by_selector(client, &selector(ROW_USAGE)?.fill("row", row_id)).await?;
+
+    let mut bill = BillBreakdown {
+        claim_date: parse_date(&claim_date)?,
+        usage: parse_use_kwh(&usage)?,
+        ..Default::default()
+    };
+
+    open_bill_detail(client, row_id).await?;
+
+    for (label, value) in bill_detail_items(client).await? {
+        // 해당 없는 항목은 금액이 비어 있거나 '-' 로 표시됨
+        if matches!(value.trim(), "" | "-") {
+            continue;
+        }
+        apply_bill_item(&mut bill, label, parse_paid(&value)?);
+    }
+
+    close_bill_detail(client).await?;
+
+    Ok(bill)
+}
+
+// 청구 행의 상세 레이어 열기
+pub async fn open_bill_detail(client: &Client, row_id: &str) -> Result<()> {
+    let detail_link = selector(ROW_DETAIL_LINK)?.fill("row", row_id);
+    click_element(client, find_selector(client, &detail_link).await?).await?;
+    wait_selector(client, &selector(BILL_DETAIL_LAYER)?).await?;
+    pp_wait_loading(client, Duration::from_secs(15)).await
+}
+
+pub async fn close_bill_detail(client: &Client) -> Result<()> {
+    let close_button = selector(BILL_DETAIL_CLOSE)?;
+    click_element(client, find_selector(client, &close_button).await?).await
+}
+
+// 상세 레이어의 (항목명, 금액) 목록
+async fn bill_detail_items(client: &Client) -> Result<Vec<(String, String)>> {
+    let layer = selector(BILL_DETAIL_LAYER)?;
+    let element = find_element(client, find_selector(client, &layer).await?).await?;
+    let script = "return Array.from(arguments[0].querySelectorAll('table tbody tr')).map(tr => { var th = tr.querySelector('th'); var tds = tr.querySelectorAll('td'); if (!th || tds.length === 0) { return null; } return [th.innerText.trim(), tds[tds.length - 1].innerText.trim()]; }).filter(item => item !== null);";
+
+    let value = client
+        .execute(script, vec![serde_json::to_value(&element)?])
+        .await
+        .map_err(|e| CrawlError::ParseFailure(format!("bill detail items: {:?}", e)))?;
+    serde_json::from_value(value)
+        .map_err(|e| CrawlError::ParseFailure(format!("bill detail items: {:?}", e)).into())
+}
+
+// 항목명으로 청구 항목 분류
+fn apply_bill_item(bill: &mut BillBreakdown, label: String, amount: i64) {
+    let key = label.replace(' ', "");
+
+    if key.contains("할인") {
+        bill.discounts.push(BillItem { label, amount });
+    } else if key.contains("기본요금") {
+        bill.basic_charge += amount;
+    } else if key.contains("전력량요금") {
+        let band = if key.contains("경부하") {
+            Some(LoadBand::OffPeak)
+        } else if key.contains("중간부하") {
+            Some(LoadBand::MidPeak)
+        } else if key.contains("최대부하") {
+            Some(LoadBand::Peak)
+        } else {
+            None
+        };
+        bill.energy_charges.push(EnergyCharge { band, amount });
+    } else if key.contains("기후환경") {
+        bill.climate_environment_charge += amount;
+    } else if key.contains("연료비조정") {
+        bill.fuel_cost_adjustment += amount;
+    } else if key.contains("부가가치세") {
+        bill.vat += amount;
+    } else if key.contains("전력산업기반기금") {
+        bill.power_industry_fund += amount;
+    } else if key.contains("TV수신료") {
+        bill.tv_license_fee += amount;
+    } else if key.contains("청구금액") {
+        bill.total = amount;
+    } else {
+        bill.other_items.push(BillItem { label, amount });
+    }
+}
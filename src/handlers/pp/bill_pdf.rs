@@ -0,0 +1,335 @@
+use anyhow::{Context, Result, anyhow};
+use axum::{
+    Json,
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::{Datelike, Months, NaiveDate};
+use fantoccini::{Client, Locator};
+use std::{
+    io::{Cursor, Write},
+    path::Path,
+    time::Duration,
+};
+
+use crate::{
+    handlers::{
+        legacy_kepco::pp_kepco::{
+            CLAIM_GRID, ROW_CLAIM_DATE, SEARCH_BUTTON, YEAR_SELECT, get_children_ids_to_map,
+            go_to_monthly_claim, parse_date,
+        },
+        pp::{
+            bill::{close_bill_detail, open_bill_detail},
+            commons::{pp_login, pp_wait_loading},
+        },
+    },
+    models::{
+        driver::selectors::SelectorKey,
+        error::response_errors_def::ErrorResponseCode,
+        handler::{
+            jobs::progress::ProgressEvent,
+            pp::bill::{BILL_PDF_MAX_MONTHS, PpBillPdfRequestBody},
+        },
+    },
+    server_init::app_state::AppState,
+    utils::{drivers::*, progress::ProgressReporter, selectors::selector},
+};
+
+const BILL_PDF_BUTTON: SelectorKey = SelectorKey::new("pp", "bill_detail", "pdf_button");
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+// 다운로드한 청구서 파일
+pub struct BillFile {
+    pub file_name: String,
+    pub bytes: Vec<u8>,
+}
+
+pub async fn get_pp_bill_pdf_handler(
+    State(state): State<AppState>,
+    Json(params): Json<PpBillPdfRequestBody>,
+) -> Result<Response, ErrorResponseCode> {
+    let months = claim_months(&params)?;
+    let user_num = file_name_part(&params.account.userNum);
+
+    let client = state
+        .pool
+        .checkout(params.test_state())
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+
+    let files = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_pp_bill_pdfs(
+                &client,
+                client.download_dir(),
+                params,
+                &months,
+                &ProgressReporter::none(),
+            )
+            .await,
+        )
+        .await?;
+
+    // 한 달이면 파일 그대로, 범위면 zip
+    if let [file] = files.as_slice() {
+        let content_type = if file.file_name.ends_with(".pdf") {
+            "application/pdf"
+        } else {
+            "application/octet-stream"
+        };
+        return Ok(attachment(
+            content_type,
+            &file.file_name,
+            file.bytes.clone(),
+        ));
+    }
+
+    let archive = zip_bill_files(&files).map_err(|e| {
+        eprintln!("Failed to zip bill files: {:?}", e);
+        ErrorResponseCode::PP_BILL_PDF
+    })?;
+    let file_name = format!(
+        "bills_{}_{}_{}.zip",
+        user_num,
+        months[0].format("%Y%m"),
+        months[months.len() - 1].format("%Y%m")
+    );
+
+    Ok(attachment("application/zip", &file_name, archive))
+}
+
+pub async fn crawl_pp_bill_pdfs(
+    client: &Client,
+    download_dir: &Path,
+    params: PpBillPdfRequestBody,
+    months: &[NaiveDate],
+    progress: &ProgressReporter,
+) -> Result<Vec<BillFile>, ErrorResponseCode> {
+    let user_num = file_name_part(&params.account.userNum);
+
+    pp_login(client, params.account, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+
+    go_to_monthly_claim(client)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_BILL_PDF.caused_by(e))?;
+
+    let total = months.len();
+    let mut files = Vec::with_capacity(total);
+    let mut selected_year = None;
+
+    for (i, month) in months.iter().enumerate() {
+        // 연도가 바뀔 때만 연도 옵션 조회
+        if selected_year != Some(month.year()) {
+            select_claim_year(client, month.year())
+                .await
+                .map_err(|e| ErrorResponseCode::PP_BILL_NOT_FOUND.caused_by(e))?;
+            selected_year = Some(month.year());
+        }
+
+        let row_id = find_claim_row(client, *month)
+            .await
+            .map_err(|e| ErrorResponseCode::PP_BILL_PDF.caused_by(e))?
+            .ok_or_else(|| {
+                eprintln!("Bill not found: {}", month.format("%Y-%m"));
+                ErrorResponseCode::PP_BILL_NOT_FOUND
+            })?;
+
+        let path = download_bill(client, download_dir, &row_id)
+            .await
+            .map_err(|e| ErrorResponseCode::PP_BILL_PDF.caused_by(e))?;
+        let bytes = std::fs::read(&path).map_err(|e| {
+            eprintln!("Failed to read downloaded bill: {:?}", e);
+            ErrorResponseCode::PP_BILL_PDF
+        })?;
+        let extension = path
+            .extension()
+            .map(|ext| file_name_part(&ext.to_string_lossy()))
+            .unwrap_or_else(|| "pdf".to_string());
+
+        files.push(BillFile {
+            file_name: format!("bill_{}_{}.{}", user_num, month.format("%Y%m"), extension),
+            bytes,
+        });
+        progress.report(ProgressEvent::PeriodParsed {
+            label: month.format("%Y-%m").to_string(),
+            current: i + 1,
+            total,
+            rows: 1,
+        });
+    }
+
+    println!("crawl_pp_bill_pdfs successfully");
+    Ok(files)
+}
+
+// claimMonth ~ endMonth 의 월 목록 (각 월의 1일)
+fn claim_months(params: &PpBillPdfRequestBody) -> Result<Vec<NaiveDate>, ErrorResponseCode> {
+    let start = parse_month(&params.claimMonth)?;
+    let end = match &params.endMonth {
+        Some(end_month) => parse_month(end_month)?,
+        None => start,
+    };
+
+    let mut months = Vec::new();
+    let mut month = start;
+    while month <= end {
+        months.push(month);
+        if months.len() > BILL_PDF_MAX_MONTHS {
+            break;
+        }
+        month = month + Months::new(1);
+    }
+
+    if months.is_empty() || months.len() > BILL_PDF_MAX_MONTHS {
+        eprintln!(
+            "Invalid claim month range: {:?} ~ {:?}",
+            params.claimMonth, params.endMonth
+        );
+        return Err(ErrorResponseCode::PP_INVALID_DATE_RANGE);
+    }
+
+    Ok(months)
+}
+
+fn parse_month(month: &str) -> Result<NaiveDate, ErrorResponseCode> {
+    NaiveDate::parse_from_str(&format!("{}-01", month.trim()), "%Y-%m-%d").map_err(|e| {
+        eprintln!("Invalid claim month '{}': {:?}", month, e);
+        ErrorResponseCode::PP_INVALID_DATE_RANGE
+    })
+}
+
+// 연도 option 선택 후 조회
+async fn select_claim_year(client: &Client, year: i32) -> Result<()> {
+    let year_select = selector(YEAR_SELECT)?;
+    let options = find_element(client, find_selector(client, &year_select).await?)
+        .await?
+        .find_all(Locator::Css("option"))
+        .await
+        .context("Failed to find options")?;
+
+    let mut selected = false;
+    for option in options {
+        if option
+            .text()
+            .await
+            .is_ok_and(|text| text.contains(&year.to_string()))
+        {
+            option.click().await.context("Failed to select option")?;
+            selected = true;
+            break;
+        }
+    }
+    if !selected {
+        return Err(anyhow!("Year option not found: {}", year));
+    }
+
+    let search_button = selector(SEARCH_BUTTON)?;
+    click_element(client, find_selector(client, &search_button).await?).await?;
+    pp_wait_loading(client, Duration::from_secs(10)).await
+}
+
+// 청구 월이 일치하는 행 ID
+async fn find_claim_row(client: &Client, month: NaiveDate) -> Result<Option<String>> {
+    let map = get_children_ids_to_map(client, &selector(CLAIM_GRID)?).await?;
+
+    for entry in map.iter() {
+        let row_id = entry.key();
+        let Ok(claim_date) =
+            text_by_selector(client, &selector(ROW_CLAIM_DATE)?.fill("row", row_id)).await
+        else {
+            continue;
+        };
+
+        if parse_date(&claim_date).is_ok_and(|date| date == month) {
+            return Ok(Some(row_id.clone()));
+        }
+    }
+
+    Ok(None)
+}
+
+// 상세 레이어의 다운로드 버튼으로 청구서 저장
+async fn download_bill(
+    client: &Client,
+    download_dir: &Path,
+    row_id: &str,
+) -> Result<std::path::PathBuf> {
+    clear_download_dir(download_dir)?;
+
+    open_bill_detail(client, row_id).await?;
+    let pdf_button = selector(BILL_PDF_BUTTON)?;
+    click_element(client, find_selector(client, &pdf_button).await?).await?;
+    let path = wait_for_download(download_dir, DOWNLOAD_TIMEOUT).await?;
+    close_bill_detail(client).await?;
+
+    Ok(path)
+}
+
+fn zip_bill_files(files: &[BillFile]) -> Result<Vec<u8>> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+
+    for file in files {
+        writer.start_file(file.file_name.as_str(), options)?;
+        writer.write_all(&file.bytes)?;
+    }
+
+    Ok(writer.finish()?.into_inner())
+}
+
+// 파일 이름에 들어가는 요청 값. Content-Disposition 헤더와 zip 항목 이름이 깨지지 않도록
+// [0-9A-Za-z_-] 외 문자는 '_' 로 바꿈
+fn file_name_part(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn attachment(content_type: &'static str, file_name: &str, bytes: Vec<u8>) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", file_name),
+            ),
+        ],
+        bytes,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_name_part_keeps_header_safe_characters_only() {
+        assert_eq!(file_name_part("0123456789"), "0123456789");
+        assert_eq!(file_name_part("ab-C_9"), "ab-C_9");
+        assert_eq!(file_name_part("12\"; x=\"y"), "12___x__y");
+        assert_eq!(file_name_part("../고객"), "_____");
+
+        let response = attachment(
+            "application/pdf",
+            &format!("bill_{}_202503.pdf", file_name_part("12\"3 번호")),
+            Vec::new(),
+        );
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"bill_12_3____202503.pdf\""
+        );
+    }
+}
@@ -1,50 +1,395 @@
-use anyhow::Result;
-use fantoccini::{Client, Locator};
-use std::time::Duration;
+use anyhow::{Result, anyhow};
+use chrono::NaiveDate;
+use fantoccini::Client;
+use std::{collections::HashSet, time::Duration};
 
-use crate::models::handler::pp::commons::{PP_URL, PpRequestBody};
-use crate::utils::drivers::*;
+use crate::models::driver::{dialog::DialogAction, selectors::SelectorKey};
+use crate::models::error::crawl_errors::CrawlError;
+use crate::models::handler::{
+    jobs::progress::ProgressEvent,
+    pp::{
+        commons::{PP_HOME, PP_INTRO, PpRequestBody},
+        customers::PpCustomer,
+    },
+};
+use crate::utils::{drivers::*, progress::ProgressReporter, selectors::selector, sites::page_url};
 
-pub async fn pp_login(client: &Client, params: PpRequestBody) -> Result<()> {
-    go_to_url(client, PP_URL).await?;
+const NOTICE_POPUP: SelectorKey = SelectorKey::new("pp", "intro", "notice_popup");
+const NOTICE_CLOSE: SelectorKey = SelectorKey::new("pp", "intro", "notice_close");
+const USER_ID_INPUT: SelectorKey = SelectorKey::new("pp", "intro", "user_id");
+const USER_PW_INPUT: SelectorKey = SelectorKey::new("pp", "intro", "user_pw");
+const LOGIN_BUTTON: SelectorKey = SelectorKey::new("pp", "intro", "login_button");
+const LOGIN_ERROR_MESSAGE: SelectorKey = SelectorKey::new("pp", "login_failure", "error_message");
+const PASSWORD_CHANGE_NOTICE: SelectorKey =
+    SelectorKey::new("pp", "login_failure", "password_change_notice");
+const LOADING_LAYER: SelectorKey = SelectorKey::new("pp", "common", "loading_layer");
+const CUSTOMER_ANCHOR: SelectorKey = SelectorKey::new("pp", "common", "customer_anchor");
+const CUSTOMER_ANCHOR_BY_NUMBER: SelectorKey =
+    SelectorKey::new("pp", "common", "customer_anchor_by_number");
+// 로그인 실패 안내 문구. 비밀번호 오류 안내에도 "5회 이상", "제한" 이 들어가므로 잠금 완료 문구만 사용
+const ACCOUNT_LOCKED_KEYWORDS: &[&str] = &["잠금되었습니다", "잠겼습니다", "잠금 상태", "잠금상태"];
+const PASSWORD_EXPIRED_KEYWORDS: &[&str] = &[
+    "비밀번호 변경",
+    "비밀번호를 변경",
+    "비밀번호 만료",
+    "변경하신 지",
+];
 
-    wait_element(client, Locator::Id("notice_auto_popup")).await?;
+pub async fn pp_login(
+    client: &Client,
+    params: PpRequestBody,
+    progress: &ProgressReporter,
+) -> Result<()> {
+    pp_sign_in(client, &params.userId, &params.userPw, progress).await?;
+    pp_select_customer(client, &params.userNum, progress).await?;
+
+    println!("pp_login successfully");
+    Ok(())
+}
+
+// 로그인까지만 수행. 고객번호 선택은 pp_select_customer
+pub async fn pp_sign_in(
+    client: &Client,
+    user_id: &str,
+    user_pw: &str,
+    progress: &ProgressReporter,
+) -> Result<()> {
+    go_to_url(client, &page_url(PP_INTRO)?).await?;
+    ensure_not_in_maintenance(client).await?;
+
+    wait_selector(client, &selector(NOTICE_POPUP)?).await?;
     //공지 팝업 비활성화
-    let _ = click_element(client, Locator::XPath("/html/body/div[2]/div[3]/label")).await;
+    let notice_close = selector(NOTICE_CLOSE)?;
+    if let Ok(locator) = find_selector(client, &notice_close).await {
+        let _ = click_element(client, locator).await;
+    }
 
-    wait_element(client, Locator::Id("RSA_USER_ID")).await?;
-    enter_value_in_element(client, Locator::Id("RSA_USER_ID"), &params.userId).await?;
-    enter_value_in_element(client, Locator::Id("RSA_USER_PWD"), &params.userPw).await?;
-    click_element(
+    let user_id_input = selector(USER_ID_INPUT)?;
+    let user_pw_input = selector(USER_PW_INPUT)?;
+    let login_button = selector(LOGIN_BUTTON)?;
+    enter_value_in_element(
         client,
-        Locator::Css("#intro_form > form > fieldset > input.intro_btn"),
+        wait_selector(client, &user_id_input).await?,
+        user_id,
     )
     .await?;
-
-    wait_for_element_display_none(
+    enter_value_in_element(
         client,
-        Locator::Id("backgroundLayer"),
-        Duration::from_secs(10),
+        find_selector(client, &user_pw_input).await?,
+        user_pw,
     )
     .await?;
+    click_element(client, find_selector(client, &login_button).await?).await?;
+
+    let loaded = pp_wait_loading(client, Duration::from_secs(10)).await;
+
+    // 실패 alert 가 떠 있으면 로딩 대기가 끝나지 않으므로 먼저 확인
+    if let Ok(Some(message)) = handle_dialog(client, DialogAction::Accept).await {
+        let error = classify_login_message(&message);
+        eprintln!("pp_sign_in rejected: {}", error);
+        return Err(error.into());
+    }
+    // 로딩이 끝나지 않았으면 화면으로 실패 원인을 판단하지 않음
+    loaded?;
+
+    if let Some(error) = pp_login_failure(client).await {
+        eprintln!("pp_sign_in rejected: {}", error);
+        return Err(error.into());
+    }
+    progress.report(ProgressEvent::LoggedIn);
+
+    println!("pp_sign_in successfully");
+    Ok(())
+}
+
+// 로그인 제출 후 화면의 오류 문구로 실패 원인 판단. 성공이면 None
+async fn pp_login_failure(client: &Client) -> Option<CrawlError> {
+    if let Some(message) = selector_text(client, LOGIN_ERROR_MESSAGE).await {
+        return Some(classify_login_message(&message));
+    }
 
-    let user_num_css = format!("ul > li > a[href='#{}']", params.userNum);
-    let selector_json = serde_json::to_string(&user_num_css).unwrap_or_default();
-    let script = format!(
-        "var a = document.querySelector({}); if (a) {{ a.click(); }}",
-        selector_json
-    );
-    
-    // user_num 클릭
-    script_execute(client, &script).await?;
-    
-    wait_for_element_display_none(
+    // 로그인은 되었지만 비밀번호 변경 안내 화면으로 이동한 경우
+    if let Some(message) = selector_text(client, PASSWORD_CHANGE_NOTICE).await {
+        let detail = message.lines().next().unwrap_or_default().to_string();
+        return Some(CrawlError::PasswordExpired(detail));
+    }
+
+    // 오류 문구 없이 로그인 폼이 그대로 남아 있음
+    let user_pw_input = selector(USER_PW_INPUT).ok()?;
+    if find_selector(client, &user_pw_input).await.is_ok() {
+        return Some(CrawlError::LoginRejected(
+            "login form is still shown".to_string(),
+        ));
+    }
+
+    None
+}
+
+// 요소가 있고 문구가 비어 있지 않을 때만 text
+async fn selector_text(client: &Client, key: SelectorKey) -> Option<String> {
+    let text = text_by_selector(client, &selector(key).ok()?).await.ok()?;
+    let text = text.trim();
+
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+// 로그인 실패 문구 분류. 알 수 없는 문구는 LoginRejected
+pub fn classify_login_message(message: &str) -> CrawlError {
+    let message = message.trim();
+    let detail = message.lines().next().unwrap_or_default().to_string();
+
+    if ACCOUNT_LOCKED_KEYWORDS
+        .iter()
+        .any(|keyword| message.contains(keyword))
+    {
+        CrawlError::AccountLocked(detail)
+    } else if PASSWORD_EXPIRED_KEYWORDS
+        .iter()
+        .any(|keyword| message.contains(keyword))
+    {
+        CrawlError::PasswordExpired(detail)
+    } else {
+        CrawlError::LoginRejected(detail)
+    }
+}
+
+// 로그인 상태에서 고객번호 전환
+pub async fn pp_select_customer(
+    client: &Client,
+    user_num: &str,
+    progress: &ProgressReporter,
+) -> Result<()> {
+    let anchor = selector(CUSTOMER_ANCHOR_BY_NUMBER)?.fill("user_num", user_num);
+    let Ok(locator) = find_selector(client, &anchor).await else {
+        eprintln!("Customer number not found: {}", user_num);
+        return Err(CrawlError::CustomerNotFound(user_num.to_string()).into());
+    };
+
+    // user_num 클릭. 메뉴 안에 숨겨진 항목이라 script 로 클릭
+    let element = find_element(client, locator).await?;
+    client
+        .execute(
+            "arguments[0].click();",
+            vec![serde_json::to_value(&element)?],
+        )
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to click customer number {}: {:?}", user_num, e);
+            anyhow!("Failed to click customer number {}: {:?}", user_num, e)
+        })?;
+
+    // 고객번호 전환 중 세션 만료 등은 alert 로 표시됨
+    with_dialog_guard(
         client,
-        Locator::Id("backgroundLayer"),
-        Duration::from_secs(10),
+        DialogAction::Accept,
+        pp_wait_loading(client, Duration::from_secs(10)),
     )
     .await?;
+    progress.report(ProgressEvent::CustomerSelected {
+        user_num: user_num.to_string(),
+    });
 
-    println!("pp_login successfully");
+    println!("pp_select_customer successfully");
     Ok(())
-}
\ No newline at end of file
+}
+
+// 로그인 후 고객번호 목록. 항목 텍스트에서 고객번호를 뺀 첫 줄은 이름, 나머지는 주소
+pub async fn pp_customers(client: &Client) -> Result<Vec<PpCustomer>> {
+    let anchor = selector(CUSTOMER_ANCHOR)?;
+    let anchors = client
+        .find_all(find_selector(client, &anchor).await?)
+        .await
+        .map_err(|e| anyhow!("Failed to find customer anchors: {:?}", e))?;
+
+    let value = client
+        .execute(
+            "return arguments[0].map(a => [a.getAttribute('href').substring(1), a.innerText || a.textContent || '']);",
+            vec![serde_json::to_value(&anchors)?],
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to read customer anchors: {:?}", e))?;
+    let entries = value
+        .as_array()
+        .ok_or_else(|| anyhow!("Expected an array of customer entries"))?;
+
+    // 같은 목록이 여러 메뉴에 반복될 수 있음
+    let mut seen = HashSet::new();
+    let mut customers = Vec::new();
+
+    for entry in entries {
+        let user_num = entry[0].as_str().unwrap_or_default().trim();
+        if user_num.is_empty() || !user_num.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        if !seen.insert(user_num.to_string()) {
+            continue;
+        }
+
+        let (name, address) = parse_customer_label(entry[1].as_str().unwrap_or_default(), user_num);
+        customers.push(PpCustomer {
+            user_num: user_num.to_string(),
+            name,
+            address,
+        });
+    }
+
+    Ok(customers)
+}
+
+pub async fn pp_customer_numbers(client: &Client) -> Result<Vec<String>> {
+    Ok(pp_customers(client)
+        .await?
+        .into_iter()
+        .map(|customer| customer.user_num)
+        .collect())
+}
+
+fn parse_customer_label(label: &str, user_num: &str) -> (Option<String>, Option<String>) {
+    let mut lines = label
+        .lines()
+        .map(|line| {
+            line.replace(user_num, "")
+                .trim_matches(|c: char| c.is_whitespace() || "()[]-/|".contains(c))
+                .to_string()
+        })
+        .filter(|line| !line.is_empty());
+
+    let name = lines.next();
+    let address = lines.collect::<Vec<String>>().join(" ");
+
+    (name, (!address.is_empty()).then_some(address))
+}
+
+// 다른 고객번호로 전환하기 전 홈으로 이동
+pub async fn pp_go_home(client: &Client) -> Result<()> {
+    go_to_url(client, &page_url(PP_HOME)?).await?;
+
+    pp_wait_loading(client, Duration::from_secs(10)).await
+}
+
+// PowerPlanner 화면 전환 후 로딩 레이어가 사라질 때까지 대기
+pub async fn pp_wait_loading(client: &Client, duration: Duration) -> Result<()> {
+    let loading_layer = selector(LOADING_LAYER)?;
+    let locator = wait_selector(client, &loading_layer).await?;
+
+    wait_for_element_display_none(client, locator, duration).await
+}
+
+// 조회 기간을 사이트가 허용하는 일수 단위로 분할 (양 끝 포함)
+pub fn split_date_range(
+    start: NaiveDate,
+    end: NaiveDate,
+    max_days: i64,
+) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut chunks = Vec::new();
+    let mut chunk_start = start;
+
+    while chunk_start <= end {
+        let chunk_end = (chunk_start + chrono::Duration::days(max_days.max(1) - 1)).min(end);
+        chunks.push((chunk_start, chunk_end));
+        chunk_start = chunk_end + chrono::Duration::days(1);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn locked_account_alerts() {
+        for message in [
+            "비밀번호를 5회 이상 잘못 입력하여 계정이 잠겼습니다.\n본인인증 후 비밀번호를 재설정해 주십시오.",
+            "로그인 5회 실패로 아이디가 잠금되었습니다. 고객센터(123)로 문의하시기 바랍니다.",
+            "잠금상태의 아이디입니다.",
+        ] {
+            assert!(
+                matches!(
+                    classify_login_message(message),
+                    CrawlError::AccountLocked(_)
+                ),
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn password_expired_alerts() {
+        for message in [
+            "비밀번호를 변경하신 지 90일이 경과하였습니다.\n개인정보 보호를 위해 비밀번호를 변경해 주십시오.",
+            "비밀번호 만료일이 지났습니다. 비밀번호 변경 후 이용해 주십시오.",
+        ] {
+            assert!(
+                matches!(
+                    classify_login_message(message),
+                    CrawlError::PasswordExpired(_)
+                ),
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn wrong_password_warnings_are_not_locks() {
+        for message in [
+            "비밀번호가 일치하지 않습니다.\n5회 이상 틀릴 경우 로그인이 제한됩니다. (현재 오류 횟수 : 2회)",
+            "아이디 또는 비밀번호를 확인해 주십시오. 비밀번호 5회 이상 오류 시 이용이 제한됩니다.",
+            "등록되지 않은 아이디입니다.",
+        ] {
+            assert!(
+                matches!(
+                    classify_login_message(message),
+                    CrawlError::LoginRejected(_)
+                ),
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn login_message_detail_is_the_first_line() {
+        let CrawlError::LoginRejected(detail) =
+            classify_login_message("  비밀번호가 일치하지 않습니다.\n(현재 오류 횟수 : 2회)")
+        else {
+            panic!("expected LoginRejected");
+        };
+        assert_eq!(detail, "비밀번호가 일치하지 않습니다.");
+    }
+
+    #[test]
+    fn date_range_is_split_into_inclusive_chunks() {
+        assert_eq!(
+            split_date_range(date(2024, 7, 1), date(2024, 7, 3), 1),
+            vec![
+                (date(2024, 7, 1), date(2024, 7, 1)),
+                (date(2024, 7, 2), date(2024, 7, 2)),
+                (date(2024, 7, 3), date(2024, 7, 3)),
+            ]
+        );
+        assert_eq!(
+            split_date_range(date(2024, 2, 25), date(2024, 3, 5), 7),
+            vec![
+                (date(2024, 2, 25), date(2024, 3, 2)),
+                (date(2024, 3, 3), date(2024, 3, 5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_or_invalid_ranges_produce_no_chunks() {
+        assert!(split_date_range(date(2024, 7, 2), date(2024, 7, 1), 7).is_empty());
+        // max_days 가 0 이하이면 하루 단위
+        assert_eq!(
+            split_date_range(date(2024, 7, 1), date(2024, 7, 2), 0).len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use axum::{Json, extract::State, response::IntoResponse};
+use fantoccini::Client;
+
+use crate::{
+    handlers::pp::commons::{pp_customers, pp_sign_in},
+    models::{
+        error::response_errors_def::ErrorResponseCode,
+        handler::pp::customers::{PpAccountRequestBody, PpCustomer},
+        response::commons::basic_response,
+    },
+    server_init::app_state::AppState,
+    utils::progress::ProgressReporter,
+};
+
+pub async fn get_pp_customers_handler(
+    State(state): State<AppState>,
+    Json(params): Json<PpAccountRequestBody>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let client = state
+        .pool
+        .checkout(params.test_state())
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+
+    let customers = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_pp_customers(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
+
+    Ok(basic_response(customers, start.elapsed()))
+}
+
+pub async fn crawl_pp_customers(
+    client: &Client,
+    params: PpAccountRequestBody,
+    progress: &ProgressReporter,
+) -> Result<Vec<PpCustomer>, ErrorResponseCode> {
+    pp_sign_in(client, &params.userId, &params.userPw, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+
+    let customers = pp_customers(client)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_CUSTOMER_LIST.caused_by(e))?;
+
+    println!("crawl_pp_customers successfully: {}", customers.len());
+    Ok(customers)
+}
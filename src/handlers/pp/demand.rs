@@ -0,0 +1,239 @@
+use anyhow::{Context, Result};
+use axum::{Json, extract::State, response::IntoResponse};
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime};
+use fantoccini::Client;
+use std::{collections::HashSet, time::Duration};
+
+use crate::{
+    handlers::pp::{
+        commons::{pp_login, pp_wait_loading},
+        user_info::pp_user_info,
+    },
+    models::{
+        driver::selectors::SelectorKey,
+        error::{crawl_errors::CrawlError, response_errors_def::ErrorResponseCode},
+        handler::{
+            jobs::progress::ProgressEvent,
+            pp::demand::{DemandRecord, PP_DEMAND_HISTORY, PpDemandHistory, PpYearRequestBody},
+        },
+        response::commons::basic_response,
+    },
+    server_init::app_state::AppState,
+    utils::{drivers::*, progress::ProgressReporter, selectors::selector, sites::page_url},
+};
+
+const SELECT_YEAR: SelectorKey = SelectorKey::new("pp", "demand_history", "select_year");
+const SEARCH_BUTTON: SelectorKey = SelectorKey::new("pp", "demand_history", "search_button");
+const DEMAND_TABLE: SelectorKey = SelectorKey::new("pp", "demand_history", "table");
+
+pub async fn get_pp_demand_history_handler(
+    State(state): State<AppState>,
+    Json(params): Json<PpYearRequestBody>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let client = state
+        .pool
+        .checkout(params.test_state())
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+
+    let history = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_pp_demand_history(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
+
+    Ok(basic_response(history, start.elapsed()))
+}
+
+pub async fn crawl_pp_demand_history(
+    client: &Client,
+    params: PpYearRequestBody,
+    progress: &ProgressReporter,
+) -> Result<PpDemandHistory, ErrorResponseCode> {
+    let year = params.year.unwrap_or_else(|| Local::now().year());
+    let user_num = params.account.userNum.clone();
+
+    pp_login(client, params.account, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+
+    let user_info = pp_user_info(client)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_USER_INFO.caused_by(e))?;
+    if user_info.is_low_voltage() {
+        eprintln!(
+            "Demand history is not available for low voltage plans: {}",
+            user_num
+        );
+        return Err(ErrorResponseCode::PP_NOT_HIGH_VOLTAGE);
+    }
+
+    let records = pp_demand_history(client, year, user_info.contract_power)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_DEMAND.caused_by(e))?;
+    progress.report(ProgressEvent::RowsFound {
+        rows: records.len(),
+    });
+
+    Ok(PpDemandHistory {
+        user_num,
+        contract_power: user_info.contract_power,
+        records,
+    })
+}
+
+async fn pp_demand_history(
+    client: &Client,
+    year: i32,
+    contract_power: f64,
+) -> Result<Vec<DemandRecord>> {
+    go_to_url(client, &page_url(PP_DEMAND_HISTORY)?).await?;
+    wait_loading(client).await?;
+
+    set_value_by_selector(client, &selector(SELECT_YEAR)?, &year.to_string()).await?;
+    let search_button = selector(SEARCH_BUTTON)?;
+    click_element(client, find_selector(client, &search_button).await?).await?;
+    wait_loading(client).await?;
+
+    // 월 | 최대수요전력 | 발생일시 | 요금적용전력 | 지상역률 | 진상역률
+    let mut records = Vec::new();
+    for cells in table_rows_by_selector(client, &selector(DEMAND_TABLE)?).await? {
+        // 조회 결과가 없다는 안내 행(셀 하나)이나 아직 값이 없는 월은 건너뜀
+        let (Some(month), Some(peak_kw)) = (cells.first(), cells.get(1)) else {
+            continue;
+        };
+        if is_blank(month) || is_blank(peak_kw) {
+            continue;
+        }
+        let peak_kw = parse_number(peak_kw)?;
+
+        records.push(DemandRecord {
+            month: parse_month(month, year)?,
+            peak_kw,
+            peak_at: optional_cell(cells.get(2), |cell| parse_peak_at(cell, year))?,
+            billing_demand_kw: optional_cell(cells.get(3), parse_number)?,
+            lagging_power_factor: optional_cell(cells.get(4), parse_number)?,
+            leading_power_factor: optional_cell(cells.get(5), parse_number)?,
+            exceeds_contract_power: contract_power > 0.0 && peak_kw > contract_power,
+        });
+    }
+
+    let mut unique_months = HashSet::new();
+    records.retain(|record| unique_months.insert(record.month));
+    records.sort_by_key(|record| std::cmp::Reverse(record.month));
+
+    println!("pp_demand_history successfully");
+    Ok(records)
+}
+
+async fn wait_loading(client: &Client) -> Result<()> {
+    pp_wait_loading(client, Duration::from_secs(15)).await
+}
+
+fn digit_groups(text: &str) -> Vec<u32> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .filter_map(|part| part.parse::<u32>().ok())
+        .collect()
+}
+
+// 값이 없는 칸은 비어 있거나 '-' 로 표시됨
+fn is_blank(text: &str) -> bool {
+    matches!(text.trim(), "" | "-")
+}
+
+// 값이 없는 칸은 None, 값이 있는데 읽지 못하면 ParseFailure
+fn optional_cell<T>(
+    cell: Option<&String>,
+    parse: impl FnOnce(&str) -> Result<T>,
+) -> Result<Option<T>> {
+    match cell {
+        Some(text) if !is_blank(text) => parse(text).map(Some),
+        _ => Ok(None),
+    }
+}
+
+// "2025년 07월", "2025-07", "7월"
+fn parse_month(text: &str, year: i32) -> Result<NaiveDate> {
+    let month = match digit_groups(text).as_slice() {
+        [y, m] => NaiveDate::from_ymd_opt(*y as i32, *m, 1),
+        [m] => NaiveDate::from_ymd_opt(year, *m, 1),
+        _ => None,
+    };
+
+    month.ok_or_else(|| CrawlError::ParseFailure(format!("demand month '{}'", text)).into())
+}
+
+// "2025-07-15 14:30", "07.15 14:30"
+fn parse_peak_at(text: &str, year: i32) -> Result<NaiveDateTime> {
+    let fields = match digit_groups(text).as_slice() {
+        [y, m, d, h, min] => Some((*y as i32, *m, *d, *h, *min)),
+        [m, d, h, min] => Some((year, *m, *d, *h, *min)),
+        _ => None,
+    };
+
+    fields
+        .and_then(|(y, m, d, h, min)| NaiveDate::from_ymd_opt(y, m, d)?.and_hms_opt(h, min, 0))
+        .ok_or_else(|| CrawlError::ParseFailure(format!("peak time '{}'", text)).into())
+}
+
+// "1,234.5 kW", "98.2%"
+fn parse_number(text: &str) -> Result<f64> {
+    let cleaned = text
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.' || *c == '-')
+        .collect::<String>();
+
+    cleaned
+        .parse::<f64>()
+        .with_context(|| CrawlError::ParseFailure(format!("demand value '{}'", text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn month_uses_the_search_year_when_the_cell_has_none() {
+        assert_eq!(parse_month("2025년 07월", 2024).unwrap(), date(2025, 7, 1));
+        assert_eq!(parse_month("2025-07", 2024).unwrap(), date(2025, 7, 1));
+        assert_eq!(parse_month("7월", 2024).unwrap(), date(2024, 7, 1));
+        assert!(parse_month("13월", 2024).is_err());
+        assert!(parse_month("합계", 2024).is_err());
+    }
+
+    #[test]
+    fn peak_at_reads_date_and_time() {
+        assert_eq!(
+            parse_peak_at("2025-07-15 14:30", 2024).unwrap(),
+            date(2025, 7, 15).and_hms_opt(14, 30, 0).unwrap()
+        );
+        assert_eq!(
+            parse_peak_at("07.15 14:30", 2024).unwrap(),
+            date(2024, 7, 15).and_hms_opt(14, 30, 0).unwrap()
+        );
+        assert!(parse_peak_at("14:30", 2024).is_err());
+        assert!(parse_peak_at("02.30 14:30", 2024).is_err());
+    }
+
+    #[test]
+    fn numbers_fail_unless_the_cell_is_blank() {
+        assert_eq!(parse_number("1,234.5 kW").unwrap(), 1234.5);
+        assert_eq!(parse_number("98.2%").unwrap(), 98.2);
+        assert!(parse_number("점검중").is_err());
+
+        assert_eq!(
+            optional_cell(Some(&"-".to_string()), parse_number).unwrap(),
+            None
+        );
+        assert_eq!(optional_cell(None, parse_number).unwrap(), None);
+        assert!(optional_cell(Some(&"점검중".to_string()), parse_number).is_err());
+    }
+}
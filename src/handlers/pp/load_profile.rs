@@ -0,0 +1,218 @@
+use anyhow::Result;
+use axum::{Json, extract::State, response::IntoResponse};
+use chrono::{NaiveDate, NaiveDateTime};
+use fantoccini::Client;
+use std::time::Duration;
+
+use crate::{
+    handlers::pp::{
+        commons::{pp_login, pp_wait_loading, split_date_range},
+        usage::parse_kwh,
+    },
+    models::{
+        driver::selectors::SelectorKey,
+        error::{crawl_errors::CrawlError, response_errors_def::ErrorResponseCode},
+        handler::{
+            jobs::progress::ProgressEvent,
+            pp::load_profile::{
+                LOAD_PROFILE_INTERVAL_MINUTES, LOAD_PROFILE_MAX_DAYS_PER_QUERY,
+                LOAD_PROFILE_MAX_RANGE_DAYS, LoadBand, LoadProfileRow, PP_REALTIME_USAGE,
+                PpDateRangeRequestBody,
+            },
+        },
+        response::commons::basic_response,
+    },
+    server_init::app_state::AppState,
+    utils::{drivers::*, progress::ProgressReporter, selectors::selector, sites::page_url},
+};
+
+pub const REALTIME_SELECT_DATE: SelectorKey =
+    SelectorKey::new("pp", "realtime_usage", "select_date");
+pub const REALTIME_SEARCH_BUTTON: SelectorKey =
+    SelectorKey::new("pp", "realtime_usage", "search_button");
+pub const REALTIME_TABLE: SelectorKey = SelectorKey::new("pp", "realtime_usage", "table");
+
+pub async fn get_pp_load_profile_handler(
+    State(state): State<AppState>,
+    Json(params): Json<PpDateRangeRequestBody>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let client = state
+        .pool
+        .checkout(params.test_state())
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+
+    let rows = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_pp_load_profile(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
+
+    Ok(basic_response(rows, start.elapsed()))
+}
+
+pub async fn crawl_pp_load_profile(
+    client: &Client,
+    params: PpDateRangeRequestBody,
+    progress: &ProgressReporter,
+) -> Result<Vec<LoadProfileRow>, ErrorResponseCode> {
+    validate_date_range(
+        params.startDate,
+        params.endDate,
+        LOAD_PROFILE_MAX_RANGE_DAYS,
+    )?;
+
+    pp_login(client, params.account, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+
+    pp_load_profile(client, params.startDate, params.endDate, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOAD_PROFILE.caused_by(e))
+}
+
+pub fn validate_date_range(
+    start: NaiveDate,
+    end: NaiveDate,
+    max_days: i64,
+) -> Result<(), ErrorResponseCode> {
+    if end < start || (end - start).num_days() + 1 > max_days {
+        eprintln!("Invalid date range: {} ~ {}", start, end);
+        return Err(ErrorResponseCode::PP_INVALID_DATE_RANGE);
+    }
+
+    Ok(())
+}
+
+async fn pp_load_profile(
+    client: &Client,
+    start: NaiveDate,
+    end: NaiveDate,
+    progress: &ProgressReporter,
+) -> Result<Vec<LoadProfileRow>> {
+    go_to_url(client, &page_url(PP_REALTIME_USAGE)?).await?;
+    pp_wait_loading(client, Duration::from_secs(15)).await?;
+
+    let chunks = split_date_range(start, end, LOAD_PROFILE_MAX_DAYS_PER_QUERY);
+    let total = chunks.len();
+    let mut rows = Vec::with_capacity(total * 96);
+
+    for (i, (chunk_start, chunk_end)) in chunks.into_iter().enumerate() {
+        // 하루 단위 조회이므로 chunk_start == chunk_end
+        let mut chunk_rows = parse_load_profile_day(client, chunk_start).await?;
+        progress.report(ProgressEvent::PeriodParsed {
+            label: format!("{} ~ {}", chunk_start, chunk_end),
+            current: i + 1,
+            total,
+            rows: chunk_rows.len(),
+        });
+        rows.append(&mut chunk_rows);
+    }
+
+    rows.sort_by_key(|row| row.timestamp);
+    rows.dedup_by_key(|row| row.timestamp);
+
+    println!("pp_load_profile successfully");
+    Ok(rows)
+}
+
+// 조회일 선택 후 15분 사용량 테이블 parsing
+async fn parse_load_profile_day(client: &Client, date: NaiveDate) -> Result<Vec<LoadProfileRow>> {
+    set_value_by_selector(
+        client,
+        &selector(REALTIME_SELECT_DATE)?,
+        &date.format("%Y-%m-%d").to_string(),
+    )
+    .await?;
+    let search_button = selector(REALTIME_SEARCH_BUTTON)?;
+    click_element(client, find_selector(client, &search_button).await?).await?;
+    pp_wait_loading(client, Duration::from_secs(15)).await?;
+
+    let table = table_rows_by_selector(client, &selector(REALTIME_TABLE)?).await?;
+
+    let mut rows = Vec::with_capacity(table.len());
+    for cells in table {
+        let (Some(time), Some(kwh)) = (cells.first(), cells.get(1)) else {
+            continue;
+        };
+        // 미래 시간대 등 값이 없는 행은 '-' 로 표시됨
+        let Some(kwh) = parse_kwh(kwh)? else {
+            continue;
+        };
+
+        let timestamp = interval_start(date, time)?;
+        rows.push(LoadProfileRow {
+            timestamp,
+            kwh,
+            band: LoadBand::of(timestamp),
+        });
+    }
+
+    Ok(rows)
+}
+
+// 테이블의 시각(HH:MM)은 구간 종료 시각. "24:00" 은 다음날 00:00
+fn interval_start(date: NaiveDate, time: &str) -> Result<NaiveDateTime> {
+    let (hour, minute) = time
+        .trim()
+        .split_once(':')
+        .ok_or_else(|| CrawlError::ParseFailure(format!("interval time '{}'", time)))?;
+    let hour = hour
+        .parse::<i64>()
+        .map_err(|e| CrawlError::ParseFailure(format!("interval hour '{}': {:?}", time, e)))?;
+    let minute = minute
+        .parse::<i64>()
+        .map_err(|e| CrawlError::ParseFailure(format!("interval minute '{}': {:?}", time, e)))?;
+
+    let end = date.and_hms_opt(0, 0, 0).unwrap_or_default()
+        + chrono::Duration::minutes(hour * 60 + minute);
+
+    Ok(end - chrono::Duration::minutes(LOAD_PROFILE_INTERVAL_MINUTES))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn interval_start_is_fifteen_minutes_before_the_row_time() {
+        let day = date(2024, 7, 10);
+
+        assert_eq!(
+            interval_start(day, "00:15").unwrap(),
+            day.and_hms_opt(0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            interval_start(day, " 13:30 ").unwrap(),
+            day.and_hms_opt(13, 15, 0).unwrap()
+        );
+        assert_eq!(
+            interval_start(day, "24:00").unwrap(),
+            day.and_hms_opt(23, 45, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn interval_start_rejects_malformed_times() {
+        let day = date(2024, 7, 10);
+
+        assert!(interval_start(day, "1315").is_err());
+        assert!(interval_start(day, "ab:15").is_err());
+        assert!(interval_start(day, "13:").is_err());
+    }
+
+    #[test]
+    fn date_range_is_limited_to_max_days() {
+        assert!(validate_date_range(date(2024, 7, 1), date(2024, 7, 31), 31).is_ok());
+        assert!(validate_date_range(date(2024, 7, 1), date(2024, 8, 1), 31).is_err());
+        assert!(validate_date_range(date(2024, 7, 2), date(2024, 7, 1), 31).is_err());
+    }
+}
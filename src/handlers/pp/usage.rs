@@ -0,0 +1,296 @@
+use anyhow::Result;
+use axum::{Json, extract::State, response::IntoResponse};
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime};
+use fantoccini::Client;
+use std::{collections::HashSet, time::Duration};
+
+use crate::{
+    handlers::pp::{
+        commons::{pp_login, pp_wait_loading},
+        load_profile::{REALTIME_SEARCH_BUTTON, REALTIME_SELECT_DATE, REALTIME_TABLE},
+    },
+    models::{
+        driver::selectors::SelectorKey,
+        error::{crawl_errors::CrawlError, response_errors_def::ErrorResponseCode},
+        handler::{
+            jobs::progress::ProgressEvent,
+            pp::{
+                load_profile::PP_REALTIME_USAGE,
+                usage::{
+                    DailyUsage, HourlyUsage, PP_DAILY_USAGE, PpMonthRequestBody, PpUsageAggregates,
+                },
+            },
+        },
+        response::commons::basic_response,
+    },
+    server_init::app_state::AppState,
+    utils::{drivers::*, progress::ProgressReporter, selectors::selector, sites::page_url},
+};
+
+const DAILY_SELECT_YEAR: SelectorKey = SelectorKey::new("pp", "daily_usage", "select_year");
+const DAILY_SELECT_MONTH: SelectorKey = SelectorKey::new("pp", "daily_usage", "select_month");
+const DAILY_SEARCH_BUTTON: SelectorKey = SelectorKey::new("pp", "daily_usage", "search_button");
+const DAILY_TABLE: SelectorKey = SelectorKey::new("pp", "daily_usage", "table");
+const REALTIME_TIME_UNIT: SelectorKey = SelectorKey::new("pp", "realtime_usage", "time_unit");
+// 실시간 사용량 페이지의 조회 단위 (60 = 1시간)
+const HOURLY_TIME_UNIT: &str = "60";
+
+pub async fn get_pp_usage_aggregates_handler(
+    State(state): State<AppState>,
+    Json(params): Json<PpMonthRequestBody>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    first_day_of_month(params.year, params.month)?;
+
+    let client = state
+        .pool
+        .checkout(params.test_state())
+        .await
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+
+    let aggregates = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_pp_usage_aggregates(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
+
+    Ok(basic_response(aggregates, start.elapsed()))
+}
+
+pub async fn crawl_pp_usage_aggregates(
+    client: &Client,
+    params: PpMonthRequestBody,
+    progress: &ProgressReporter,
+) -> Result<PpUsageAggregates, ErrorResponseCode> {
+    let first_day = first_day_of_month(params.year, params.month)?;
+    let user_num = params.account.userNum.clone();
+
+    pp_login(client, params.account, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
+
+    let daily = pp_daily_usage(client, first_day)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_USAGE.caused_by(e))?;
+    progress.report(ProgressEvent::RowsFound { rows: daily.len() });
+
+    let hourly = pp_hourly_usage(client, first_day, progress)
+        .await
+        .map_err(|e| ErrorResponseCode::PP_USAGE.caused_by(e))?;
+
+    Ok(PpUsageAggregates {
+        user_num,
+        daily,
+        hourly,
+    })
+}
+
+fn first_day_of_month(year: i32, month: u32) -> Result<NaiveDate, ErrorResponseCode> {
+    NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(|| {
+        eprintln!("Invalid month: {}-{}", year, month);
+        ErrorResponseCode::PP_INVALID_DATE_RANGE
+    })
+}
+
+// 해당 월의 날짜들. 오늘 이후는 데이터가 없으므로 제외
+fn days_of_month(first_day: NaiveDate) -> Vec<NaiveDate> {
+    let today = Local::now().date_naive();
+
+    first_day
+        .iter_days()
+        .take_while(|day| day.month() == first_day.month() && *day <= today)
+        .collect()
+}
+
+async fn pp_daily_usage(client: &Client, first_day: NaiveDate) -> Result<Vec<DailyUsage>> {
+    go_to_url(client, &page_url(PP_DAILY_USAGE)?).await?;
+    wait_loading(client).await?;
+
+    set_value_by_selector(
+        client,
+        &selector(DAILY_SELECT_YEAR)?,
+        &first_day.year().to_string(),
+    )
+    .await?;
+    set_value_by_selector(
+        client,
+        &selector(DAILY_SELECT_MONTH)?,
+        &format!("{:02}", first_day.month()),
+    )
+    .await?;
+    let search_button = selector(DAILY_SEARCH_BUTTON)?;
+    click_element(client, find_selector(client, &search_button).await?).await?;
+    wait_loading(client).await?;
+
+    let mut daily = Vec::new();
+    for cells in table_rows_by_selector(client, &selector(DAILY_TABLE)?).await? {
+        // 조회 결과 없음 안내 등 셀이 하나뿐인 행
+        let (Some(day), Some(kwh)) = (cells.first(), cells.get(1)) else {
+            continue;
+        };
+        let Some(kwh) = parse_kwh(kwh)? else {
+            continue;
+        };
+        daily.push(DailyUsage {
+            date: parse_day_of_month(first_day, day)?,
+            kwh,
+        });
+    }
+
+    dedup_and_sort_by_key(&mut daily, |usage| usage.date);
+
+    println!("pp_daily_usage successfully");
+    Ok(daily)
+}
+
+async fn pp_hourly_usage(
+    client: &Client,
+    first_day: NaiveDate,
+    progress: &ProgressReporter,
+) -> Result<Vec<HourlyUsage>> {
+    go_to_url(client, &page_url(PP_REALTIME_USAGE)?).await?;
+    wait_loading(client).await?;
+    set_value_by_selector(client, &selector(REALTIME_TIME_UNIT)?, HOURLY_TIME_UNIT).await?;
+    let select_date = selector(REALTIME_SELECT_DATE)?;
+    let search_button = selector(REALTIME_SEARCH_BUTTON)?;
+    let table = selector(REALTIME_TABLE)?;
+
+    let days = days_of_month(first_day);
+    let total = days.len();
+    let mut hourly = Vec::with_capacity(total * 24);
+
+    for (i, day) in days.into_iter().enumerate() {
+        set_value_by_selector(client, &select_date, &day.format("%Y-%m-%d").to_string()).await?;
+        click_element(client, find_selector(client, &search_button).await?).await?;
+        wait_loading(client).await?;
+
+        let mut rows = Vec::new();
+        for cells in table_rows_by_selector(client, &table).await? {
+            let (Some(time), Some(kwh)) = (cells.first(), cells.get(1)) else {
+                continue;
+            };
+            let Some(kwh) = parse_kwh(kwh)? else {
+                continue;
+            };
+            rows.push(HourlyUsage {
+                timestamp: hour_start(day, time)?,
+                kwh,
+            });
+        }
+
+        progress.report(ProgressEvent::PeriodParsed {
+            label: day.to_string(),
+            current: i + 1,
+            total,
+            rows: rows.len(),
+        });
+        hourly.append(&mut rows);
+    }
+
+    dedup_and_sort_by_key(&mut hourly, |usage| usage.timestamp);
+
+    println!("pp_hourly_usage successfully");
+    Ok(hourly)
+}
+
+// claim_date 와 같은 방식으로 중복 제거 후 최신순 정렬
+fn dedup_and_sort_by_key<T, K, F>(data_vec: &mut Vec<T>, key: F)
+where
+    K: Eq + std::hash::Hash + Ord + Copy,
+    F: Fn(&T) -> K,
+{
+    let mut unique_keys = HashSet::new();
+    data_vec.retain(|entry| unique_keys.insert(key(entry)));
+
+    data_vec.sort_by_key(|entry| std::cmp::Reverse(key(entry)));
+}
+
+async fn wait_loading(client: &Client) -> Result<()> {
+    pp_wait_loading(client, Duration::from_secs(15)).await
+}
+
+// "2025-07-01", "07.01", "1일" 등 마지막 숫자를 일자로 사용
+fn parse_day_of_month(first_day: NaiveDate, text: &str) -> Result<NaiveDate> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .rfind(|part| !part.is_empty())
+        .and_then(|day| day.parse::<u32>().ok())
+        .and_then(|day| first_day.with_day(day))
+        .ok_or_else(|| CrawlError::ParseFailure(format!("day '{}'", text)).into())
+}
+
+// 미래 날짜 / 시간대 등 값이 없는 칸은 '-' 또는 빈 칸으로 표시되며 None
+pub fn parse_kwh(text: &str) -> Result<Option<f64>> {
+    let cleaned = text.replace(',', "");
+    let cleaned = cleaned.trim();
+    if cleaned.is_empty() || cleaned == "-" {
+        return Ok(None);
+    }
+
+    cleaned
+        .parse::<f64>()
+        .map(Some)
+        .map_err(|e| CrawlError::ParseFailure(format!("kWh '{}': {:?}", text, e)).into())
+}
+
+// 테이블의 시각은 구간 종료 시각 ("01:00" 은 00:00 ~ 01:00, "24:00" 은 23:00 ~ 24:00)
+fn hour_start(day: NaiveDate, time: &str) -> Result<NaiveDateTime> {
+    let hour = time
+        .trim()
+        .split(':')
+        .next()
+        .and_then(|hour| hour.parse::<u32>().ok())
+        .filter(|hour| (1..=24).contains(hour))
+        .ok_or_else(|| CrawlError::ParseFailure(format!("hour '{}'", time)))?;
+
+    Ok(day.and_hms_opt(hour - 1, 0, 0).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn hour_start_is_one_hour_before_the_row_time() {
+        let day = date(2025, 7, 10);
+
+        assert_eq!(
+            hour_start(day, "01:00").unwrap(),
+            day.and_hms_opt(0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            hour_start(day, " 24:00 ").unwrap(),
+            day.and_hms_opt(23, 0, 0).unwrap()
+        );
+        assert!(hour_start(day, "00:00").is_err());
+        assert!(hour_start(day, "25:00").is_err());
+        assert!(hour_start(day, "합계").is_err());
+    }
+
+    #[test]
+    fn daily_and_hourly_cells_fail_the_same_way() {
+        let first_day = date(2025, 7, 1);
+
+        assert_eq!(
+            parse_day_of_month(first_day, "2025-07-15").unwrap(),
+            date(2025, 7, 15)
+        );
+        assert_eq!(
+            parse_day_of_month(first_day, "3일").unwrap(),
+            date(2025, 7, 3)
+        );
+        assert!(parse_day_of_month(first_day, "합계").is_err());
+        assert!(parse_day_of_month(first_day, "07.32").is_err());
+
+        assert_eq!(parse_kwh("1,234.5").unwrap(), Some(1234.5));
+        assert_eq!(parse_kwh(" - ").unwrap(), None);
+        assert_eq!(parse_kwh("").unwrap(), None);
+        assert!(parse_kwh("12kWh?").is_err());
+    }
+}
@@ -1,11 +1,11 @@
 use std::time::Duration;
 use crate::{
-    handlers::pp::commons::pp_login,
+    handlers::pp::commons::{pp_login, pp_wait_loading},
     models::{
-        driver::chromes::LOCAL_URL,
-        error::response_errors_def::ErrorResponseCode,
+        driver::selectors::SelectorKey,
+        error::{crawl_errors::CrawlError, response_errors_def::ErrorResponseCode},
         handler::pp::{
-            commons::{PpRequestBody, USER_INFO_URL, USER_SELECT_CHARGE_URL},
+            commons::{PP_SELECT_CHARGE, PP_USER_INFO, PpRequestBody},
             user_info::{
                 CONTRACT_TYPE, CONTRACT_TYPE_ID, PURPOSE, PURPOSE_ID, UserInfo, contract_vec,
                 purpose_vec,
@@ -13,84 +13,79 @@ use crate::{
         },
         response::commons::basic_response,
     },
-    utils::drivers::*,
+    server_init::app_state::AppState,
+    utils::{drivers::*, progress::ProgressReporter, selectors::selector, sites::page_url},
 };
 use anyhow::{Result, anyhow};
-use axum::{Json, response::IntoResponse};
-use fantoccini::{Client, Locator};
+use axum::{Json, extract::State, response::IntoResponse};
+use fantoccini::Client;
 use regex::Regex;
 
+const BASIC_TABLE: SelectorKey = SelectorKey::new("pp", "user_info", "basic_table");
+const USER_NUMBER: SelectorKey = SelectorKey::new("pp", "user_info", "user_number");
+const CONTRACT: SelectorKey = SelectorKey::new("pp", "user_info", "contract");
+const CONTRACT_POWER: SelectorKey = SelectorKey::new("pp", "user_info", "contract_power");
+const INSPECTION_DAY: SelectorKey = SelectorKey::new("pp", "user_info", "inspection_day");
+const INSTRUMENT_TAB: SelectorKey = SelectorKey::new("pp", "user_info", "instrument_tab");
+const INSTRUMENT_NUMBER: SelectorKey = SelectorKey::new("pp", "user_info", "instrument_number");
+const PRICING_PLAN: SelectorKey = SelectorKey::new("pp", "select_charge", "pricing_plan");
+
 pub async fn get_user_info_handler(
+    State(state): State<AppState>,
     Json(params): Json<PpRequestBody>,
 ) -> Result<impl IntoResponse, ErrorResponseCode> {
     let start = std::time::Instant::now();
 
-    let client = create_client(LOCAL_URL, PpRequestBody::test_state(&params))
+    let client = state
+        .pool
+        .checkout(PpRequestBody::test_state(&params))
         .await
-        .map_err(|_| ErrorResponseCode::CREATE_CLIENT)?;
+        .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+
+    let user_info = state
+        .artifacts
+        .capture_on_error(
+            &client,
+            crawl_user_info(&client, params, &ProgressReporter::none()).await,
+        )
+        .await?;
 
-    pp_login(&client, params)
-        .await
-        .map_err(|_| ErrorResponseCode::PP_LOGIN)?;
+    Ok(basic_response(user_info, start.elapsed()))
+}
 
-    let user_info = pp_user_info(&client)
+pub async fn crawl_user_info(
+    client: &Client,
+    params: PpRequestBody,
+    progress: &ProgressReporter,
+) -> Result<UserInfo, ErrorResponseCode> {
+    pp_login(client, params, progress)
         .await
-        .map_err(|_| ErrorResponseCode::PP_USER_INFO)?;
+        .map_err(|e| ErrorResponseCode::PP_LOGIN.caused_by(e))?;
 
-    clean_client(&client)
+    pp_user_info(client)
         .await
-        .map_err(|_| ErrorResponseCode::CLEAN_CLIENT)?;
-
-    Ok(basic_response(user_info, start.elapsed()))
+        .map_err(|e| ErrorResponseCode::PP_USER_INFO.caused_by(e))
 }
 
-async fn pp_user_info(client: &Client) -> Result<UserInfo> {
-    go_to_url(client, USER_INFO_URL).await?;
-    wait_element(&client, Locator::Css("#table2")).await?;
-
-    let user_number = text_element(
-        &client,
-        Locator::Css(
-            "#contents > div.table_info > table > tbody > tr:nth-child(1) > td:nth-child(2)",
-        ),
-    )
-    .await?;
-
-    let contract = text_element(
-        &client,
-        Locator::Css(
-            "#contents > div.table_info > table > tbody > tr:nth-child(2) > td:nth-child(2)",
-        ),
-    )
-    .await?;
-
-    let contract_power = text_element(
-        &client,
-        Locator::Css(
-            "#contents > div.table_info > table > tbody > tr:nth-child(2) > td:nth-child(4)",
-        ),
-    )
-    .await?;
-
-    let inspection_day = text_element(
-        &client,
-        Locator::Css("#table2 > tbody > tr:nth-child(1) > td:nth-child(4)"),
-    )
-    .await?;
-
-    click_element(&client, Locator::Css("#tab3 > a")).await?;
-
-    let instrument_number = text_element(
-        &client,
-        Locator::Css("#table3 > tbody > tr:nth-child(1) > td:nth-child(2)"),
-    )
-    .await?;
+pub async fn pp_user_info(client: &Client) -> Result<UserInfo> {
+    go_to_url(client, &page_url(PP_USER_INFO)?).await?;
+    wait_selector(client, &selector(BASIC_TABLE)?).await?;
+
+    let user_number = text_by_selector(client, &selector(USER_NUMBER)?).await?;
+    let contract = text_by_selector(client, &selector(CONTRACT)?).await?;
+    let contract_power = text_by_selector(client, &selector(CONTRACT_POWER)?).await?;
+    let inspection_day = text_by_selector(client, &selector(INSPECTION_DAY)?).await?;
+
+    let instrument_tab = selector(INSTRUMENT_TAB)?;
+    click_element(client, find_selector(client, &instrument_tab).await?).await?;
+
+    let instrument_number = text_by_selector(client, &selector(INSTRUMENT_NUMBER)?).await?;
 
     println!("pp_user_info successfully");
     Ok(UserInfo {
         user_number,
         contract_type_id: extract_id(&contract, CONTRACT_TYPE)?,
-        purpose_id: pp_user_select_charge_info(&client, &contract).await?,
+        purpose_id: pp_user_select_charge_info(client, &contract).await?,
         contract_power: parse_day(&contract_power)? as f64,
         inspection_day: parse_day(&inspection_day)?,
         instrument_number,
@@ -98,16 +93,11 @@ async fn pp_user_info(client: &Client) -> Result<UserInfo> {
 }
 
 async fn pp_user_select_charge_info(client: &Client, contract: &str) -> Result<i16> {
-    go_to_url(client, USER_SELECT_CHARGE_URL).await?;
+    go_to_url(client, &page_url(PP_SELECT_CHARGE)?).await?;
 
-    wait_for_element_display_none(
-        client,
-        Locator::Id("backgroundLayer"),
-        Duration::from_secs(15),
-    )
-        .await?;
+    pp_wait_loading(client, Duration::from_secs(15)).await?;
 
-    let pricing_plan = text_element(&client, Locator::Id("spanCNTR_KND_NM")).await?;
+    let pricing_plan = text_by_selector(client, &selector(PRICING_PLAN)?).await?;
     println!("pricing_plan: {}", pricing_plan);
     println!("contract: {}", contract);
 
@@ -119,7 +109,7 @@ async fn pp_user_select_charge_info(client: &Client, contract: &str) -> Result<i
     println!("result: {}", result);
 
     println!("pp_user_select_charge_info successfully");
-    Ok(extract_id(&result, PURPOSE)?)
+    extract_id(&result, PURPOSE)
 }
 
 fn parse_day(input: &str) -> Result<i16> {
@@ -129,7 +119,7 @@ fn parse_day(input: &str) -> Result<i16> {
         let day_str = mat.as_str();
         let day = day_str
             .parse::<i16>()
-            .map_err(|e| anyhow!("Failed to parse day '{}': {:?}", day_str, e))?;
+            .map_err(|e| CrawlError::ParseFailure(format!("day '{}': {:?}", day_str, e)))?;
         return Ok(day);
     }
 
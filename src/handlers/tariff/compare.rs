@@ -0,0 +1,26 @@
+use axum::{Json, response::IntoResponse};
+
+use crate::{
+    models::{
+        error::response_errors_def::ErrorResponseCode, response::commons::basic_response,
+        tariff::compare::TariffCompareRequestBody,
+    },
+    utils::tariff::compare_plans,
+};
+
+// 월별 사용량 기준으로 선택 가능한 요금제별 합계를 절감액 순으로 반환
+pub async fn post_tariff_compare_handler(
+    Json(params): Json<TariffCompareRequestBody>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let comparison = compare_plans(
+        params.contract_type_id,
+        params.purpose_id,
+        params.contract_power,
+        &params.months,
+        params.include_other_use,
+    )?;
+
+    Ok(basic_response(comparison, start.elapsed()))
+}
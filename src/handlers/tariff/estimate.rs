@@ -0,0 +1,24 @@
+use axum::{Json, response::IntoResponse};
+
+use crate::{
+    models::{
+        error::response_errors_def::ErrorResponseCode,
+        response::commons::basic_response,
+        tariff::estimate::{TariffEstimate, TariffEstimateRequestBody},
+    },
+    utils::tariff::calculate_bill,
+};
+
+// 크롤링 없이 요금표로 청구액 계산. paid 가 있으면 차이와 이상 여부 포함
+pub async fn post_tariff_estimate_handler(
+    Json(params): Json<TariffEstimateRequestBody>,
+) -> Result<impl IntoResponse, ErrorResponseCode> {
+    let start = std::time::Instant::now();
+
+    let expected = calculate_bill(&params.input)?;
+
+    Ok(basic_response(
+        TariffEstimate::new(expected, params.paid),
+        start.elapsed(),
+    ))
+}
@@ -11,23 +11,56 @@ mod handlers {
     }
 
     pub mod pp {
+        pub mod batch;
+        pub mod bill;
+        pub mod bill_pdf;
         pub mod commons;
+        pub mod customers;
+        pub mod demand;
+        pub mod load_profile;
+        pub mod usage;
         pub mod user_info;
     }
+
+    pub mod driver {
+        pub mod canary;
+        pub mod status;
+    }
+
+    pub mod jobs {
+        pub mod crawl_jobs;
+    }
+
+    pub mod tariff {
+        pub mod compare;
+        pub mod estimate;
+    }
 }
 
 mod models {
+    pub mod auth {
+        pub mod api_keys;
+    }
+
     pub mod response {
         pub mod commons;
     }
 
     pub mod error {
+        pub mod crawl_errors;
         pub mod response_errors;
         pub mod response_errors_def;
     }
 
     pub mod driver {
+        pub mod artifacts;
+        pub mod canary;
         pub mod chromes;
+        pub mod dialog;
+        pub mod pool;
+        pub mod selectors;
+        pub mod sites;
+        pub mod supervisor;
     }
 
     pub mod handler {
@@ -37,22 +70,52 @@ mod models {
         }
 
         pub mod pp {
+            pub mod batch;
+            pub mod bill;
             pub mod commons;
+            pub mod customers;
+            pub mod demand;
+            pub mod load_profile;
+            pub mod usage;
             pub mod user_info;
         }
+
+        pub mod jobs {
+            pub mod crawl_jobs;
+            pub mod progress;
+        }
+    }
+
+    pub mod tariff {
+        pub mod compare;
+        pub mod estimate;
+        pub mod rates;
     }
 }
 
 mod server_init {
+    pub mod app_state;
+    pub mod config;
+    #[allow(clippy::module_inception)]
     pub mod server_init;
 }
 
 mod utils {
+    pub mod auth;
+    pub mod artifacts;
+    pub mod canary;
     pub mod drivers;
+    pub mod jobs;
+    pub mod progress;
+    pub mod selectors;
+    pub mod sites;
+    pub mod supervisor;
+    pub mod tariff;
 }
 
+use crate::server_init::config::ServerConfig;
 use crate::server_init::server_init::server_initializer;
-use crate::utils::drivers::start_chromedriver;
+use crate::utils::supervisor::DriverSupervisor;
 
 // 도쿄는 Axum 웹 프레임워크를 위한 비동기 런타임을 제공함. num_cpus 라이브러리를 사용하여 논리코어 개수에 따라 자동으로 thread pool 생성, request 분배함.
 // Tokio is an asynchronous runtime, used here to run the Axum web framework. Automatically detects the number of logical cores to generate a thread pool of the appropraite size and distribute requests.
@@ -61,8 +124,8 @@ async fn main() -> Result<()> {
     let start: tokio::time::Instant = tokio::time::Instant::now();
     let server_start_time: DateTime<Utc> = Utc::now();
 
-    // 로컬 테스팅을 위한 환경변수파일 로딩. 런타임 오류 발생할 수 있으니 EC2/ECS 배포시 반드시 비활성화!
-    // An environment variable loader for local testing. Disable when distributing to AWS! Will result in a runtime error if not disabled.
+    // 로컬 테스팅을 위한 환경변수파일 로딩. 파일이 없으면 프로세스 환경변수만 사용 (컨테이너 배포).
+    // An environment variable loader for local testing. Without a .env file only the process environment is used (container deployments).
     match dotenv() {
         Ok(path_buf) => {
             println!(
@@ -71,6 +134,9 @@ async fn main() -> Result<()> {
                 start.elapsed()
             );
         }
+        Err(e) if e.not_found() => {
+            println!("No .env file found, using process environment");
+        }
         Err(e) => {
             return Err(anyhow!(
                 "Dotenvy could not load .env file: {}",
@@ -79,14 +145,24 @@ async fn main() -> Result<()> {
         }
     }
 
-    let _chromedriver = start_chromedriver()
-        .await
-        .map_err(|e| anyhow!("Failed to start chromedriver: {}", e))?;
-    // let chromedriver_process = Arc::new(Mutex::new(chromedriver_process));\
+    // CLI flag > 환경변수 > 설정 파일 순으로 서버 설정을 읽고 검증.
+    // Load and validate the server config: CLI flags > env vars > config file.
+    let config = ServerConfig::load().map_err(|e| anyhow!("Invalid server config: {}", e))?;
+
+    // chromedriver 는 supervisor 가 띄우고 죽으면 재시작함.
+    // The supervisor launches chromedriver and restarts it whenever it dies.
+    let driver = DriverSupervisor::new(&config.driver_path, config.driver_port);
+    let _driver_supervisor = driver.spawn();
 
     // 유닛 테스트를 위하여 서버 시작 부분 논리는 분리해놓음
     // Server initialization logic separated for potential future unit testing.
-    match server_initializer(start, server_start_time).await {
+    let server_result = server_initializer(start, server_start_time, driver.clone(), config).await;
+
+    // graceful shutdown 이후 chromedriver 와 chrome 자식 프로세스 정리.
+    // Kill chromedriver and its Chrome children once the server has drained.
+    driver.shutdown().await;
+
+    match server_result {
         Ok(server_initializer_result) => {
             println!(
                 "Server successfully terminated: {}",
@@ -99,3 +175,10 @@ async fn main() -> Result<()> {
         }
     }
 }
+
+// 로컬 mock 사이트를 이용한 크롤러 테스트
+#[cfg(test)]
+mod testing {
+    pub mod mock_site;
+    pub mod pp_fixtures;
+}
@@ -0,0 +1,119 @@
+use anyhow::{Result, anyhow};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+pub const API_KEY_HEADER: &str = "x-api-key";
+// 헤더를 붙일 수 없는 브라우저 WebSocket 용. Sec-WebSocket-Protocol: x-api-key, <key>
+pub const API_KEY_PROTOCOL: &str = "x-api-key";
+const DEFAULT_MAX_CONCURRENT: usize = 2;
+const DEFAULT_DAILY_QUOTA: u64 = 1000;
+
+// 클라이언트별 제한. 0 이면 해당 키로 크롤링 불가 (조회 전용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientLimits {
+    pub max_concurrent: usize,
+    // 크롤링 요청 수. pp/batch 는 고객번호 수만큼 셈
+    pub daily_quota: u64,
+}
+
+impl Default for ClientLimits {
+    fn default() -> Self {
+        ClientLimits {
+            max_concurrent: DEFAULT_MAX_CONCURRENT,
+            daily_quota: DEFAULT_DAILY_QUOTA,
+        }
+    }
+}
+
+// api_keys.toml 의 [clients.<name>] 항목. 키 교체를 위해 여러 개 등록 가능
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApiClientEntry {
+    pub keys: Vec<String>,
+    pub max_concurrent: Option<usize>,
+    pub daily_quota: Option<u64>,
+    #[serde(default)]
+    pub admin: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyFile {
+    #[serde(default)]
+    pub clients: BTreeMap<String, ApiClientEntry>,
+}
+
+impl ApiKeyFile {
+    pub fn parse(text: &str) -> Result<Self> {
+        toml::from_str(text).map_err(|e| anyhow!("Invalid api key file: {}", e))
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read api key file {}: {:?}", path.display(), e))?;
+
+        Self::parse(&text).map_err(|e| anyhow!("{} ({})", e, path.display()))
+    }
+
+    // "name:key,name:key" 형식. 제한은 기본값, admin 아님
+    pub fn from_list(list: &str) -> Result<Self> {
+        let mut file = ApiKeyFile::default();
+        for item in list.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let (name, key) = item
+                .split_once(':')
+                .ok_or_else(|| anyhow!("Invalid api key entry, expected name:key"))?;
+            file.clients
+                .entry(name.trim().to_string())
+                .or_default()
+                .keys
+                .push(key.trim().to_string());
+        }
+
+        Ok(file)
+    }
+
+    // 같은 이름의 클라이언트는 self 가 우선
+    pub fn merge(mut self, lower: ApiKeyFile) -> Self {
+        for (name, entry) in lower.clients {
+            self.clients.entry(name).or_insert(entry);
+        }
+        self
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let mut seen = HashSet::new();
+        for (name, entry) in &self.clients {
+            if entry.keys.is_empty() {
+                return Err(anyhow!("api client {} has no keys", name));
+            }
+            for key in &entry.keys {
+                if key.is_empty() {
+                    return Err(anyhow!("api client {} has an empty key", name));
+                }
+                if !seen.insert(key.as_str()) {
+                    return Err(anyhow!(
+                        "api client {} reuses a key of another client",
+                        name
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// API 키 설정. clients 가 비어 있으면 서버 시작 불가
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyConfig {
+    pub clients: ApiKeyFile,
+    pub defaults: ClientLimits,
+}
+
+// 인증된 요청에 extension 으로 붙는 클라이언트 정보
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ClientIdentity {
+    pub name: String,
+    pub admin: bool,
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde_derive::Serialize;
+use std::path::PathBuf;
+
+pub const SCREENSHOT_FILE: &str = "screenshot.png";
+pub const PAGE_SOURCE_FILE: &str = "page.html";
+pub const CONSOLE_LOG_FILE: &str = "console.json";
+pub const META_FILE: &str = "meta.json";
+
+// 실패 artifact 저장 설정
+#[derive(Debug, Clone)]
+pub struct ArtifactConfig {
+    pub dir: PathBuf,
+}
+
+// <dir>/<artifact_id>/meta.json
+#[derive(Serialize, Debug, Clone)]
+pub struct ArtifactMeta {
+    pub artifact_id: String,
+    pub code: u16,
+    pub message: String,
+    pub url: Option<String>,
+    pub files: Vec<String>,
+    pub captured_at: DateTime<Utc>,
+}
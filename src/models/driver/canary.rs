@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use serde_derive::Serialize;
+use std::time::Duration;
+
+use crate::models::handler::pp::commons::PpRequestBody;
+
+pub const DEFAULT_CANARY_INTERVAL_SECS: u64 = 6 * 60 * 60;
+
+// selector drift 점검 설정. 계정이 없으면 점검 불가, interval 0 이면 스케줄 비활성화
+#[derive(Debug, Clone)]
+pub struct CanaryConfig {
+    pub account: Option<PpRequestBody>,
+    pub interval: Option<Duration>,
+}
+
+impl CanaryConfig {
+    pub fn new(account: Option<PpRequestBody>, interval_secs: u64) -> Self {
+        let interval =
+            (account.is_some() && interval_secs > 0).then(|| Duration::from_secs(interval_secs));
+
+        CanaryConfig { account, interval }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectorStatus {
+    // 첫 번째 selector 로 찾음
+    Ok,
+    // fallback 으로만 찾음. 마크업이 바뀌었을 가능성
+    Fallback,
+    Missing,
+    // placeholder 를 채울 값이 없거나 페이지에 도달하지 못함
+    Skipped,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SelectorCheck {
+    pub key: String,
+    pub status: SelectorStatus,
+    pub matched: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PageCheck {
+    pub page: String,
+    pub visited: bool,
+    pub url: Option<String>,
+    pub selectors: Vec<SelectorCheck>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SiteCheck {
+    pub site: String,
+    pub version: Option<String>,
+    pub error: Option<String>,
+    pub pages: Vec<PageCheck>,
+}
+
+impl SiteCheck {
+    pub fn healthy(&self) -> bool {
+        self.error.is_none()
+            && self.pages.iter().all(|page| {
+                page.selectors.iter().all(|check| {
+                    matches!(check.status, SelectorStatus::Ok | SelectorStatus::Skipped)
+                })
+            })
+    }
+}
+
+// /admin/canary 응답
+#[derive(Serialize, Debug, Clone)]
+pub struct CanaryReport {
+    pub healthy: bool,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub sites: Vec<SiteCheck>,
+}
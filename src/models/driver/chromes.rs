@@ -1,9 +1,10 @@
 use anyhow::{Result, anyhow};
 use serde_derive::Serialize;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tempfile::TempDir;
 
-const BINARY_PATH: &str = "CHROME_BINARY_PATH";
-const BINARY_PATH_TEST: &str = "CHROME_BINARY_PATH_TEST";
 const HEADLESS: &str = "--headless";
 const DISABLE_GPU: &str = "--disable-gpu";
 const DISABLE_TRANSLATE: &str = "--disable-translate";
@@ -29,18 +30,28 @@ const COMMON_ARGS: &[&str] = &[
     WINDOW_SIZE,
 ];
 
-pub const LOCAL_URL: &str = "http://localhost:4450";
+// Chrome 실행 설정
+#[derive(Debug, Clone)]
+pub struct BrowserConfig {
+    pub binary: String,
+    pub test_binary: String,
+    pub no_sandbox: bool,
+    pub page_load_timeout: Duration,
+}
 
 #[derive(Serialize)]
 pub struct ChromeOptions {
     binary: String,
     args: Vec<String>,
+    prefs: Value,
+    #[serde(skip)]
+    download_dir: PathBuf,
     #[serde(skip)]
     pub _user_data_tempdir: Option<TempDir>,
 }
 
 impl ChromeOptions {
-    fn with_binary(binary: String) -> Result<Self> {
+    fn with_binary(binary: String, no_sandbox: bool) -> Result<Self> {
         let tmp_dir = TempDir::new()
             .map_err(|e| anyhow!("Failed to create temp dir: {}", e))?;
 
@@ -48,20 +59,35 @@ impl ChromeOptions {
             .iter()
             .map(|&s| s.to_string())
             .collect::<Vec<_>>();
+        if no_sandbox {
+            args.push(NO_SANDBOX.to_string());
+        }
         args.push(format!("--user-data-dir={}", tmp_dir.path().display()));
 
-        Ok(ChromeOptions { binary, args, _user_data_tempdir: Some(tmp_dir) })
+        // 다운로드 파일은 세션 temp dir 아래에 저장
+        let download_dir = tmp_dir.path().join("downloads");
+        std::fs::create_dir_all(&download_dir)
+            .map_err(|e| anyhow!("Failed to create download dir: {}", e))?;
+        let prefs = json!({
+            "download.default_directory": download_dir.display().to_string(),
+            "download.prompt_for_download": false,
+            "download.directory_upgrade": true,
+            "plugins.always_open_pdf_externally": true,
+        });
+
+        Ok(ChromeOptions { binary, args, prefs, download_dir, _user_data_tempdir: Some(tmp_dir) })
     }
 
-    pub fn new(test: bool) -> Result<Self> {
-        let mut key = BINARY_PATH_TEST;
+    pub fn download_dir(&self) -> &Path {
+        &self.download_dir
+    }
+
+    pub fn new(browser: &BrowserConfig, test: bool) -> Result<Self> {
+        let mut binary = &browser.test_binary;
         if test {
-            key = BINARY_PATH
+            binary = &browser.binary
         }
 
-        let binary_path =
-            std::env::var(key).map_err(|e| anyhow!("Failed to get ENV BINARY_PATH: {}", e))?;
-
-        Self::with_binary(binary_path)
+        Self::with_binary(binary.clone(), browser.no_sandbox)
     }
 }
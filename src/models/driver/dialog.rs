@@ -0,0 +1,11 @@
+use std::time::Duration;
+
+// 대기 중 열린 dialog 확인 주기
+pub const DIALOG_POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+// alert / confirm dialog 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogAction {
+    Accept,
+    Dismiss,
+}
@@ -0,0 +1,50 @@
+use anyhow::{Result, anyhow};
+use std::time::Duration;
+
+const DEFAULT_MIN_SIZE: usize = 1;
+const DEFAULT_MAX_SIZE: usize = 4;
+const DEFAULT_CHECKOUT_TIMEOUT_SECS: u64 = 60;
+const DEFAULT_HEALTH_CHECK_INTERVAL_SECS: u64 = 30;
+
+// WebDriver 세션 풀 설정
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    pub min_size: usize,
+    pub max_size: usize,
+    pub checkout_timeout: Duration,
+    pub health_check_interval: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        PoolConfig {
+            min_size: DEFAULT_MIN_SIZE,
+            max_size: DEFAULT_MAX_SIZE,
+            checkout_timeout: Duration::from_secs(DEFAULT_CHECKOUT_TIMEOUT_SECS),
+            health_check_interval: Duration::from_secs(DEFAULT_HEALTH_CHECK_INTERVAL_SECS),
+        }
+    }
+}
+
+impl PoolConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.max_size == 0 {
+            return Err(anyhow!("pool_max_size must be greater than 0"));
+        }
+        if self.min_size > self.max_size {
+            return Err(anyhow!(
+                "pool_min_size ({}) must not exceed pool_max_size ({})",
+                self.min_size,
+                self.max_size
+            ));
+        }
+        // tokio::time::interval 은 0 이면 panic 하고 maintenance task 가 조용히 멈춤
+        if self.health_check_interval.is_zero() {
+            return Err(anyhow!(
+                "pool_health_check_interval_secs must be greater than 0"
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,318 @@
+use anyhow::{Result, anyhow};
+use fantoccini::Locator;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const DEFAULT_SELECTORS_PATH: &str = "selectors.toml";
+pub const DEFAULT_SELECTORS_RELOAD_INTERVAL_SECS: u64 = 10;
+
+// 빌드 시 포함되는 기본 selector. 파일이 없으면 이 값을 사용
+pub const DEFAULT_SELECTORS: &str = include_str!("../../../selectors.toml");
+
+// selector registry 설정
+#[derive(Debug, Clone)]
+pub struct SelectorConfig {
+    pub path: PathBuf,
+    pub reload_interval: Duration,
+}
+
+impl Default for SelectorConfig {
+    fn default() -> Self {
+        SelectorConfig {
+            path: PathBuf::from(DEFAULT_SELECTORS_PATH),
+            reload_interval: Duration::from_secs(DEFAULT_SELECTORS_RELOAD_INTERVAL_SECS),
+        }
+    }
+}
+
+// selectors.toml 전체. [sites.<site>] 아래 version 과 [sites.<site>.pages.<page>] 항목
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SelectorFile {
+    pub sites: BTreeMap<String, SiteSelectors>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SiteSelectors {
+    pub version: String,
+    #[serde(default)]
+    pub pages: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+}
+
+impl SelectorFile {
+    pub fn parse(text: &str) -> Result<Self> {
+        let file: SelectorFile =
+            toml::from_str(text).map_err(|e| anyhow!("Failed to parse selectors: {}", e))?;
+
+        // 잘못된 항목은 로딩 시점에 거부
+        for (site, selectors) in &file.sites {
+            for (page, entries) in &selectors.pages {
+                for (name, alternatives) in entries {
+                    if alternatives.is_empty() {
+                        return Err(anyhow!("Selector {}.{}.{} is empty", site, page, name));
+                    }
+                    for alternative in alternatives {
+                        alternative.parse::<SelectorSpec>().map_err(|e| {
+                            anyhow!("Invalid selector {}.{}.{}: {}", site, page, name, e)
+                        })?;
+                    }
+                }
+            }
+        }
+
+        Ok(file)
+    }
+
+    // 등록된 모든 site.page.name
+    pub fn keys(&self) -> BTreeSet<String> {
+        self.sites
+            .iter()
+            .flat_map(|(site, selectors)| {
+                selectors.pages.iter().flat_map(move |(page, entries)| {
+                    entries
+                        .keys()
+                        .map(move |name| format!("{}.{}.{}", site, page, name))
+                })
+            })
+            .collect()
+    }
+
+    // required 에는 있지만 self 에는 없는 항목
+    pub fn missing_keys(&self, required: &SelectorFile) -> Vec<String> {
+        required.keys().difference(&self.keys()).cloned().collect()
+    }
+
+    pub fn get(&self, key: SelectorKey) -> Option<Selector> {
+        let alternatives = self
+            .sites
+            .get(key.site)?
+            .pages
+            .get(key.page)?
+            .get(key.name)?
+            .iter()
+            .filter_map(|alternative| alternative.parse().ok())
+            .collect();
+
+        Some(Selector {
+            key: key.to_string(),
+            alternatives,
+        })
+    }
+
+    // 페이지에 등록된 selector 전체
+    pub fn page(&self, site: &str, page: &str) -> Vec<Selector> {
+        let Some(entries) = self
+            .sites
+            .get(site)
+            .and_then(|selectors| selectors.pages.get(page))
+        else {
+            return Vec::new();
+        };
+
+        entries
+            .iter()
+            .map(|(name, alternatives)| Selector {
+                key: format!("{}.{}.{}", site, page, name),
+                alternatives: alternatives
+                    .iter()
+                    .filter_map(|alternative| alternative.parse().ok())
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+// site.page.name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectorKey {
+    pub site: &'static str,
+    pub page: &'static str,
+    pub name: &'static str,
+}
+
+impl SelectorKey {
+    pub const fn new(site: &'static str, page: &'static str, name: &'static str) -> Self {
+        SelectorKey { site, page, name }
+    }
+}
+
+impl fmt::Display for SelectorKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.site, self.page, self.name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorStrategy {
+    Css,
+    XPath,
+    Id,
+}
+
+// "css:...", "xpath:...", "id:..." 형식의 selector 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorSpec {
+    pub strategy: SelectorStrategy,
+    pub value: String,
+}
+
+impl SelectorSpec {
+    pub fn locator(&self) -> Locator<'_> {
+        match self.strategy {
+            SelectorStrategy::Css => Locator::Css(&self.value),
+            SelectorStrategy::XPath => Locator::XPath(&self.value),
+            SelectorStrategy::Id => Locator::Id(&self.value),
+        }
+    }
+}
+
+impl FromStr for SelectorSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (strategy, value) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow!("'{}' has no strategy prefix (css:, xpath:, id:)", s))?;
+        let strategy = match strategy.trim() {
+            "css" => SelectorStrategy::Css,
+            "xpath" => SelectorStrategy::XPath,
+            "id" => SelectorStrategy::Id,
+            other => return Err(anyhow!("Unknown selector strategy '{}'", other)),
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(anyhow!("'{}' has an empty value", s));
+        }
+
+        Ok(SelectorSpec {
+            strategy,
+            value: value.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for SelectorSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let strategy = match self.strategy {
+            SelectorStrategy::Css => "css",
+            SelectorStrategy::XPath => "xpath",
+            SelectorStrategy::Id => "id",
+        };
+        write!(f, "{}:{}", strategy, self.value)
+    }
+}
+
+// registry 에서 꺼낸 항목. 앞에서부터 시도하고 나머지는 fallback
+#[derive(Debug, Clone)]
+pub struct Selector {
+    pub key: String,
+    pub alternatives: Vec<SelectorSpec>,
+}
+
+impl Selector {
+    // "{name}" placeholder 치환
+    pub fn fill(&self, name: &str, value: &str) -> Selector {
+        let placeholder = format!("{{{}}}", name);
+        Selector {
+            key: self.key.clone(),
+            alternatives: self
+                .alternatives
+                .iter()
+                .map(|spec| SelectorSpec {
+                    strategy: spec.strategy,
+                    value: spec.value.replace(&placeholder, value),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = r#"
+        [sites.pp]
+        version = "2025-06-01"
+
+        [sites.pp.pages.monthly_claim]
+        grid = ["xpath://*[@id='grid']/tbody", "css:#grid > tbody", "id:grid"]
+        row_usage = ["xpath://*[@id='{row}']/td[4]", "css:#{row} > td:nth-child(4)"]
+    "#;
+
+    const GRID: SelectorKey = SelectorKey::new("pp", "monthly_claim", "grid");
+    const ROW_USAGE: SelectorKey = SelectorKey::new("pp", "monthly_claim", "row_usage");
+
+    #[test]
+    fn parse_rejects_invalid_entries() {
+        assert!(SelectorFile::parse(FILE).is_ok());
+        assert!(SelectorFile::parse(DEFAULT_SELECTORS).is_ok());
+
+        let invalid = [
+            "[sites.pp]\nversion = \"1\"\n[sites.pp.pages.a]\nb = []",
+            "[sites.pp]\nversion = \"1\"\n[sites.pp.pages.a]\nb = [\"#grid\"]",
+            "[sites.pp]\nversion = \"1\"\n[sites.pp.pages.a]\nb = [\"name:grid\"]",
+            "[sites.pp]\nversion = \"1\"\n[sites.pp.pages.a]\nb = [\"css: \"]",
+            "[sites.pp.pages.a]\nb = [\"id:grid\"]",
+        ];
+        for text in invalid {
+            assert!(SelectorFile::parse(text).is_err(), "accepted {}", text);
+        }
+    }
+
+    #[test]
+    fn alternatives_keep_the_file_order() {
+        let file = SelectorFile::parse(FILE).unwrap();
+        let grid = file.get(GRID).unwrap();
+
+        assert_eq!(grid.key, "pp.monthly_claim.grid");
+        assert_eq!(
+            grid.alternatives
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "xpath://*[@id='grid']/tbody",
+                "css:#grid > tbody",
+                "id:grid"
+            ]
+        );
+        assert!(
+            file.get(SelectorKey::new("pp", "monthly_claim", "unknown"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn fill_replaces_the_placeholder_in_every_alternative() {
+        let file = SelectorFile::parse(FILE).unwrap();
+        let usage = file.get(ROW_USAGE).unwrap().fill("row", "row_3");
+
+        assert_eq!(usage.key, "pp.monthly_claim.row_usage");
+        assert_eq!(usage.alternatives[0].value, "//*[@id='row_3']/td[4]");
+        assert_eq!(usage.alternatives[1].value, "#row_3 > td:nth-child(4)");
+        // 다른 placeholder 는 그대로 둠
+        assert_eq!(
+            usage.fill("parent", "x").alternatives[0].value,
+            "//*[@id='row_3']/td[4]"
+        );
+    }
+
+    #[test]
+    fn missing_keys_are_reported() {
+        let file = SelectorFile::parse(FILE).unwrap();
+        let partial = SelectorFile::parse(
+            "[sites.pp]\nversion = \"2\"\n[sites.pp.pages.monthly_claim]\ngrid = [\"id:grid\"]",
+        )
+        .unwrap();
+
+        assert!(file.missing_keys(&partial).is_empty());
+        assert_eq!(
+            partial.missing_keys(&file),
+            ["pp.monthly_claim.row_usage".to_string()]
+        );
+    }
+}
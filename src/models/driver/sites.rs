@@ -0,0 +1,148 @@
+use anyhow::{Result, anyhow};
+use serde_derive::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use url::Url;
+
+pub const DEFAULT_SITES_PATH: &str = "sites.toml";
+
+// 빌드 시 포함되는 기본 주소. 설정 파일은 이 값을 덮어씀
+pub const DEFAULT_SITES: &str = include_str!("../../../sites.toml");
+
+// 사이트 주소 설정. base_urls 는 사이트 이름별로 파일보다 우선
+#[derive(Debug, Clone)]
+pub struct SiteConfig {
+    pub path: PathBuf,
+    pub base_urls: BTreeMap<String, String>,
+}
+
+impl Default for SiteConfig {
+    fn default() -> Self {
+        SiteConfig {
+            path: PathBuf::from(DEFAULT_SITES_PATH),
+            base_urls: BTreeMap::new(),
+        }
+    }
+}
+
+// sites.toml 원본. 덮어쓰기용 파일은 일부 항목만 있어도 됨
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct SiteFile {
+    #[serde(default)]
+    pub sites: BTreeMap<String, SiteEntry>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct SiteEntry {
+    pub base_url: Option<String>,
+    #[serde(default)]
+    pub pages: BTreeMap<String, String>,
+}
+
+impl SiteFile {
+    pub fn parse(text: &str) -> Result<Self> {
+        toml::from_str(text).map_err(|e| anyhow!("Failed to parse sites: {}", e))
+    }
+
+    // 같은 site 는 base_url 과 page 단위로 덮어씀
+    pub fn merge(mut self, other: SiteFile) -> Self {
+        for (name, entry) in other.sites {
+            let site = self.sites.entry(name).or_default();
+            if entry.base_url.is_some() {
+                site.base_url = entry.base_url;
+            }
+            site.pages.extend(entry.pages);
+        }
+        self
+    }
+
+    pub fn with_base_url(mut self, site: &str, base_url: &str) -> Self {
+        self.sites.entry(site.to_string()).or_default().base_url = Some(base_url.to_string());
+        self
+    }
+}
+
+// 검증이 끝난 사이트 주소
+#[derive(Debug, Clone)]
+pub struct Sites {
+    sites: BTreeMap<String, Site>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Site {
+    pub base_url: Url,
+    pub pages: BTreeMap<String, String>,
+}
+
+impl Sites {
+    pub fn resolve(file: SiteFile) -> Result<Self> {
+        let mut sites = BTreeMap::new();
+        for (name, entry) in file.sites {
+            let base_url = entry
+                .base_url
+                .ok_or_else(|| anyhow!("Site {} has no base_url", name))?;
+            let base_url = Url::parse(&base_url)
+                .map_err(|e| anyhow!("Invalid base_url for site {} ({}): {}", name, base_url, e))?;
+            if base_url.cannot_be_a_base() {
+                return Err(anyhow!("Invalid base_url for site {}: {}", name, base_url));
+            }
+            for (page, path) in &entry.pages {
+                base_url
+                    .join(path)
+                    .map_err(|e| anyhow!("Invalid path for {}.{} ({}): {}", name, page, path, e))?;
+            }
+
+            sites.insert(
+                name,
+                Site {
+                    base_url,
+                    pages: entry.pages,
+                },
+            );
+        }
+
+        Ok(Sites { sites })
+    }
+
+    pub fn site(&self, site: &str) -> Option<&Site> {
+        self.sites.get(site)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Site)> {
+        self.sites.iter()
+    }
+}
+
+impl Site {
+    // 사이트 기준 상대 경로 또는 절대 주소
+    pub fn join(&self, path: &str) -> Result<String> {
+        self.base_url
+            .join(path)
+            .map(|url| url.to_string())
+            .map_err(|e| anyhow!("Invalid path {} for {}: {}", path, self.base_url, e))
+    }
+
+    pub fn page_url(&self, page: &str) -> Option<String> {
+        self.join(self.pages.get(page)?).ok()
+    }
+}
+
+// site.page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SitePage {
+    pub site: &'static str,
+    pub page: &'static str,
+}
+
+impl SitePage {
+    pub const fn new(site: &'static str, page: &'static str) -> Self {
+        SitePage { site, page }
+    }
+}
+
+impl fmt::Display for SitePage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.site, self.page)
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde_derive::Serialize;
+
+// chromedriver 프로세스 상태
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DriverState {
+    Starting,
+    Ready,
+    Unhealthy,
+    Restarting,
+    Stopped,
+}
+
+// /driver/status 응답
+#[derive(Serialize, Debug, Clone)]
+pub struct DriverStatus {
+    pub state: DriverState,
+    pub pid: Option<u32>,
+    pub port: u16,
+    pub restarts: u32,
+    pub last_error: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl DriverStatus {
+    pub fn new(port: u16) -> Self {
+        DriverStatus {
+            state: DriverState::Starting,
+            pid: None,
+            port,
+            restarts: 0,
+            last_error: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
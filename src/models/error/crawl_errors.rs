@@ -0,0 +1,59 @@
+use std::fmt;
+
+use super::response_errors_def::ErrorResponseCode;
+
+// 크롤링 실패 원인 분류. anyhow::Error 의 context 로 전달되고 핸들러에서 ErrorResponseCode 로 변환
+#[derive(Debug, Clone)]
+pub enum CrawlError {
+    DriverStart(String),
+    SessionCreate(String),
+    NavigationTimeout(String),
+    LoginRejected(String),
+    AccountLocked(String),
+    PasswordExpired(String),
+    CustomerNotFound(String),
+    SiteMaintenance(String),
+    SelectorNotFound(String),
+    ParseFailure(String),
+    UnexpectedDialog(String),
+}
+
+impl CrawlError {
+    pub fn response_code(&self) -> ErrorResponseCode {
+        match self {
+            CrawlError::DriverStart(_) => ErrorResponseCode::DRIVER_START,
+            CrawlError::SessionCreate(_) => ErrorResponseCode::SESSION_CREATE,
+            CrawlError::NavigationTimeout(_) => ErrorResponseCode::NAVIGATION_TIMEOUT,
+            CrawlError::LoginRejected(_) => ErrorResponseCode::LOGIN_REJECTED,
+            CrawlError::AccountLocked(_) => ErrorResponseCode::ACCOUNT_LOCKED,
+            CrawlError::PasswordExpired(_) => ErrorResponseCode::PASSWORD_EXPIRED,
+            CrawlError::CustomerNotFound(_) => ErrorResponseCode::PP_CUSTOMER_NOT_FOUND,
+            CrawlError::SiteMaintenance(_) => ErrorResponseCode::SITE_MAINTENANCE,
+            CrawlError::SelectorNotFound(_) => ErrorResponseCode::SELECTOR_NOT_FOUND,
+            CrawlError::ParseFailure(_) => ErrorResponseCode::PARSE_FAILURE,
+            CrawlError::UnexpectedDialog(_) => ErrorResponseCode::UNEXPECTED_DIALOG,
+        }
+    }
+}
+
+impl fmt::Display for CrawlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrawlError::DriverStart(detail) => write!(f, "driver start failed: {}", detail),
+            CrawlError::SessionCreate(detail) => write!(f, "session creation failed: {}", detail),
+            CrawlError::NavigationTimeout(detail) => write!(f, "navigation timed out: {}", detail),
+            CrawlError::LoginRejected(detail) => write!(f, "login rejected: {}", detail),
+            CrawlError::AccountLocked(detail) => write!(f, "account locked: {}", detail),
+            CrawlError::PasswordExpired(detail) => write!(f, "password expired: {}", detail),
+            CrawlError::CustomerNotFound(detail) => {
+                write!(f, "customer number not found: {}", detail)
+            }
+            CrawlError::SiteMaintenance(detail) => write!(f, "site under maintenance: {}", detail),
+            CrawlError::SelectorNotFound(detail) => write!(f, "selector not found: {}", detail),
+            CrawlError::ParseFailure(detail) => write!(f, "parse failure: {}", detail),
+            CrawlError::UnexpectedDialog(detail) => write!(f, "unexpected dialog: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for CrawlError {}
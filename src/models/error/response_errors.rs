@@ -2,24 +2,317 @@ use super::response_errors_def::ErrorResponseCode;
 use axum::http::StatusCode;
 
 impl ErrorResponseCode {
-    pub const CREATE_CLIENT: ErrorResponseCode = ErrorResponseCode {
+    pub const CHECKOUT_SESSION: ErrorResponseCode = ErrorResponseCode {
         code: 5001,
-        message: "Could not create_client!",
-        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        message: "Could not checkout a browser session!",
+        status_code: StatusCode::SERVICE_UNAVAILABLE,
+        artifact_id: None,
     };
     pub const PP_LOGIN: ErrorResponseCode = ErrorResponseCode {
         code: 5002,
         message: "Could not pp_login!",
         status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
     };
     pub const PP_USER_INFO: ErrorResponseCode = ErrorResponseCode {
         code: 5003,
         message: "Could not pp_user_info!",
         status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
     };
-    pub const CLEAN_CLIENT: ErrorResponseCode = ErrorResponseCode {
-        code: 5003,
-        message: "Could not clean_client!",
+    pub const PP_PAID_DATA: ErrorResponseCode = ErrorResponseCode {
+        code: 5004,
+        message: "Could not pp_paid_data!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const KEPCO_LOGIN: ErrorResponseCode = ErrorResponseCode {
+        code: 5005,
+        message: "Could not kepco_login!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const KEPCO_PAID_DATA: ErrorResponseCode = ErrorResponseCode {
+        code: 5006,
+        message: "Could not kepco_paid_data!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const JOB_NOT_FOUND: ErrorResponseCode = ErrorResponseCode {
+        code: 4004,
+        message: "Could not find the crawl job!",
+        status_code: StatusCode::NOT_FOUND,
+        artifact_id: None,
+    };
+    pub const SERIALIZE_RESULT: ErrorResponseCode = ErrorResponseCode {
+        code: 5007,
+        message: "Could not serialize the crawl result!",
         status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const PP_CUSTOMER_NOT_FOUND: ErrorResponseCode = ErrorResponseCode {
+        code: 4006,
+        message: "Could not find the customer number!",
+        status_code: StatusCode::NOT_FOUND,
+        artifact_id: None,
+    };
+    pub const PP_CUSTOMER_LIST: ErrorResponseCode = ErrorResponseCode {
+        code: 5008,
+        message: "Could not pp_customer_list!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const PP_INVALID_DATE_RANGE: ErrorResponseCode = ErrorResponseCode {
+        code: 4007,
+        message: "Invalid date range!",
+        status_code: StatusCode::BAD_REQUEST,
+        artifact_id: None,
+    };
+    pub const PP_LOAD_PROFILE: ErrorResponseCode = ErrorResponseCode {
+        code: 5009,
+        message: "Could not pp_load_profile!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const PP_USAGE: ErrorResponseCode = ErrorResponseCode {
+        code: 5010,
+        message: "Could not pp_usage!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const PP_BILL_BREAKDOWN: ErrorResponseCode = ErrorResponseCode {
+        code: 5011,
+        message: "Could not pp_bill_breakdown!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const PP_BILL_NOT_FOUND: ErrorResponseCode = ErrorResponseCode {
+        code: 4008,
+        message: "Could not find the bill for the claim month!",
+        status_code: StatusCode::NOT_FOUND,
+        artifact_id: None,
+    };
+    pub const PP_BILL_PDF: ErrorResponseCode = ErrorResponseCode {
+        code: 5012,
+        message: "Could not pp_bill_pdf!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const PP_NOT_HIGH_VOLTAGE: ErrorResponseCode = ErrorResponseCode {
+        code: 4009,
+        message: "Only available for high voltage plans!",
+        status_code: StatusCode::BAD_REQUEST,
+        artifact_id: None,
+    };
+    pub const PP_DEMAND: ErrorResponseCode = ErrorResponseCode {
+        code: 5013,
+        message: "Could not pp_demand!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const TARIFF_RATE_NOT_FOUND: ErrorResponseCode = ErrorResponseCode {
+        code: 4010,
+        message: "Could not find the rate table for the plan!",
+        status_code: StatusCode::BAD_REQUEST,
+        artifact_id: None,
+    };
+    pub const TARIFF_USAGE_REQUIRED: ErrorResponseCode = ErrorResponseCode {
+        code: 4011,
+        message: "Usage (or time band usage for time-of-use plans) is required!",
+        status_code: StatusCode::BAD_REQUEST,
+        artifact_id: None,
     };
+    pub const DRIVER_START: ErrorResponseCode = ErrorResponseCode {
+        code: 5014,
+        message: "ChromeDriver is not ready!",
+        status_code: StatusCode::SERVICE_UNAVAILABLE,
+        artifact_id: None,
+    };
+    pub const SESSION_CREATE: ErrorResponseCode = ErrorResponseCode {
+        code: 5015,
+        message: "Could not create a browser session!",
+        status_code: StatusCode::SERVICE_UNAVAILABLE,
+        artifact_id: None,
+    };
+    pub const NAVIGATION_TIMEOUT: ErrorResponseCode = ErrorResponseCode {
+        code: 5016,
+        message: "Timed out waiting for the target site!",
+        status_code: StatusCode::GATEWAY_TIMEOUT,
+        artifact_id: None,
+    };
+    pub const LOGIN_REJECTED: ErrorResponseCode = ErrorResponseCode {
+        code: 4012,
+        message: "Wrong user id or password!",
+        status_code: StatusCode::UNAUTHORIZED,
+        artifact_id: None,
+    };
+    pub const SITE_MAINTENANCE: ErrorResponseCode = ErrorResponseCode {
+        code: 5017,
+        message: "The target site is under maintenance!",
+        status_code: StatusCode::SERVICE_UNAVAILABLE,
+        artifact_id: None,
+    };
+    pub const SELECTOR_NOT_FOUND: ErrorResponseCode = ErrorResponseCode {
+        code: 5018,
+        message: "Could not find an expected element on the target site!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const PARSE_FAILURE: ErrorResponseCode = ErrorResponseCode {
+        code: 5019,
+        message: "Could not parse data from the target site!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const ACCOUNT_LOCKED: ErrorResponseCode = ErrorResponseCode {
+        code: 4013,
+        message: "The account is locked!",
+        status_code: StatusCode::FORBIDDEN,
+        artifact_id: None,
+    };
+    pub const PASSWORD_EXPIRED: ErrorResponseCode = ErrorResponseCode {
+        code: 4014,
+        message: "The password has expired and must be changed!",
+        status_code: StatusCode::FORBIDDEN,
+        artifact_id: None,
+    };
+    pub const UNEXPECTED_DIALOG: ErrorResponseCode = ErrorResponseCode {
+        code: 5020,
+        message: "The target site showed an unexpected dialog!",
+        status_code: StatusCode::INTERNAL_SERVER_ERROR,
+        artifact_id: None,
+    };
+    pub const CANARY_NOT_CONFIGURED: ErrorResponseCode = ErrorResponseCode {
+        code: 5021,
+        message: "Canary account is not configured!",
+        status_code: StatusCode::SERVICE_UNAVAILABLE,
+        artifact_id: None,
+    };
+    pub const CANARY_RUNNING: ErrorResponseCode = ErrorResponseCode {
+        code: 4015,
+        message: "A canary run is already in progress!",
+        status_code: StatusCode::CONFLICT,
+        artifact_id: None,
+    };
+    pub const CANARY_REPORT_NOT_FOUND: ErrorResponseCode = ErrorResponseCode {
+        code: 4016,
+        message: "No canary report yet!",
+        status_code: StatusCode::NOT_FOUND,
+        artifact_id: None,
+    };
+    pub const INVALID_API_KEY: ErrorResponseCode = ErrorResponseCode {
+        code: 4017,
+        message: "Missing or invalid x-api-key!",
+        status_code: StatusCode::UNAUTHORIZED,
+        artifact_id: None,
+    };
+    pub const TOO_MANY_CONCURRENT_CRAWLS: ErrorResponseCode = ErrorResponseCode {
+        code: 4018,
+        message: "Too many concurrent crawls for this api key!",
+        status_code: StatusCode::TOO_MANY_REQUESTS,
+        artifact_id: None,
+    };
+    pub const DAILY_QUOTA_EXCEEDED: ErrorResponseCode = ErrorResponseCode {
+        code: 4019,
+        message: "Daily crawl quota exceeded for this api key!",
+        status_code: StatusCode::TOO_MANY_REQUESTS,
+        artifact_id: None,
+    };
+    pub const ADMIN_REQUIRED: ErrorResponseCode = ErrorResponseCode {
+        code: 4020,
+        message: "This api key is not allowed to use admin routes!",
+        status_code: StatusCode::FORBIDDEN,
+        artifact_id: None,
+    };
+
+    // 모든 응답 코드. 상수를 추가하면 여기에도 추가 (테스트에서 코드 중복 검사)
+    #[cfg(test)]
+    pub const ALL: &[ErrorResponseCode] = &[
+        ErrorResponseCode::CHECKOUT_SESSION,
+        ErrorResponseCode::PP_LOGIN,
+        ErrorResponseCode::PP_USER_INFO,
+        ErrorResponseCode::PP_PAID_DATA,
+        ErrorResponseCode::KEPCO_LOGIN,
+        ErrorResponseCode::KEPCO_PAID_DATA,
+        ErrorResponseCode::JOB_NOT_FOUND,
+        ErrorResponseCode::SERIALIZE_RESULT,
+        ErrorResponseCode::PP_CUSTOMER_NOT_FOUND,
+        ErrorResponseCode::PP_CUSTOMER_LIST,
+        ErrorResponseCode::PP_INVALID_DATE_RANGE,
+        ErrorResponseCode::PP_LOAD_PROFILE,
+        ErrorResponseCode::PP_USAGE,
+        ErrorResponseCode::PP_BILL_BREAKDOWN,
+        ErrorResponseCode::PP_BILL_NOT_FOUND,
+        ErrorResponseCode::PP_BILL_PDF,
+        ErrorResponseCode::PP_NOT_HIGH_VOLTAGE,
+        ErrorResponseCode::PP_DEMAND,
+        ErrorResponseCode::TARIFF_RATE_NOT_FOUND,
+        ErrorResponseCode::TARIFF_USAGE_REQUIRED,
+        ErrorResponseCode::DRIVER_START,
+        ErrorResponseCode::SESSION_CREATE,
+        ErrorResponseCode::NAVIGATION_TIMEOUT,
+        ErrorResponseCode::LOGIN_REJECTED,
+        ErrorResponseCode::SITE_MAINTENANCE,
+        ErrorResponseCode::SELECTOR_NOT_FOUND,
+        ErrorResponseCode::PARSE_FAILURE,
+        ErrorResponseCode::ACCOUNT_LOCKED,
+        ErrorResponseCode::PASSWORD_EXPIRED,
+        ErrorResponseCode::UNEXPECTED_DIALOG,
+        ErrorResponseCode::CANARY_NOT_CONFIGURED,
+        ErrorResponseCode::CANARY_RUNNING,
+        ErrorResponseCode::CANARY_REPORT_NOT_FOUND,
+        ErrorResponseCode::INVALID_API_KEY,
+        ErrorResponseCode::TOO_MANY_CONCURRENT_CRAWLS,
+        ErrorResponseCode::DAILY_QUOTA_EXCEEDED,
+        ErrorResponseCode::ADMIN_REQUIRED,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorResponseCode;
+    use crate::models::error::crawl_errors::CrawlError;
+    use std::collections::HashMap;
+
+    #[test]
+    fn error_codes_are_unique() {
+        let mut seen = HashMap::new();
+        for error in ErrorResponseCode::ALL {
+            if let Some(previous) = seen.insert(error.code, error.message) {
+                panic!(
+                    "\"{}\" and \"{}\" share error code {}",
+                    previous, error.message, error.code
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn crawl_error_codes_are_listed() {
+        let errors = [
+            CrawlError::DriverStart(String::new()),
+            CrawlError::SessionCreate(String::new()),
+            CrawlError::NavigationTimeout(String::new()),
+            CrawlError::LoginRejected(String::new()),
+            CrawlError::AccountLocked(String::new()),
+            CrawlError::PasswordExpired(String::new()),
+            CrawlError::CustomerNotFound(String::new()),
+            CrawlError::SiteMaintenance(String::new()),
+            CrawlError::SelectorNotFound(String::new()),
+            CrawlError::ParseFailure(String::new()),
+            CrawlError::UnexpectedDialog(String::new()),
+        ];
+
+        for error in errors {
+            let code = error.response_code().code;
+            assert!(
+                ErrorResponseCode::ALL
+                    .iter()
+                    .any(|listed| listed.code == code),
+                "{:?} maps to unlisted code {}",
+                error,
+                code
+            );
+        }
+    }
 }
@@ -3,29 +3,56 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
-use serde_json::json;
+use serde_json::{Value, json};
+
+use super::crawl_errors::CrawlError;
 
 #[derive(Clone)]
 pub struct ErrorResponseCode {
     pub code: u16,
     pub message: &'static str,
     pub status_code: StatusCode,
+    // 실패 시점 screenshot 등을 저장한 artifact 디렉터리 id
+    pub artifact_id: Option<String>,
 }
 
-// Use for dynamic error messages.
-pub struct ErrorResponseCodeOwnedStr {
-    pub code: u16,
-    pub message: String,
-    pub status_code: StatusCode,
-}
-
-impl IntoResponse for ErrorResponseCode {
-    fn into_response(self) -> Response {
-        let body = json!({
+impl ErrorResponseCode {
+    pub fn to_json(&self) -> Value {
+        let mut body = json!({
             "code": self.code,
             "message": self.message,
             "status": self.status_code.as_u16()
         });
+        if let Some(artifact_id) = &self.artifact_id {
+            body["artifact_id"] = json!(artifact_id);
+        }
+        body
+    }
+
+    pub fn with_artifact_id(mut self, artifact_id: String) -> ErrorResponseCode {
+        self.artifact_id = Some(artifact_id);
+        self
+    }
+}
+
+impl ErrorResponseCode {
+    // 원인 체인에 CrawlError 가 있으면 그 코드를, 없으면 self 를 사용. 원인은 로그로 남김
+    pub fn caused_by(self, cause: impl Into<anyhow::Error>) -> ErrorResponseCode {
+        let cause = cause.into();
+        let code = cause
+            .chain()
+            .find_map(|e| e.downcast_ref::<CrawlError>())
+            .map(CrawlError::response_code)
+            .unwrap_or(self);
+
+        eprintln!("[{}] {}: {:?}", code.code, code.message, cause);
+        code
+    }
+}
+
+impl IntoResponse for ErrorResponseCode {
+    fn into_response(self) -> Response {
+        let body = self.to_json();
         (self.status_code, Json(body)).into_response()
     }
 }
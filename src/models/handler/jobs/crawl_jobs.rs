@@ -0,0 +1,263 @@
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::models::auth::api_keys::ClientIdentity;
+use crate::models::handler::{
+    jobs::progress::ProgressEvent,
+    pp::{
+        batch::PpBatchRequestBody, commons::PpRequestBody, customers::PpAccountRequestBody,
+        demand::PpYearRequestBody, load_profile::PpDateRangeRequestBody, usage::PpMonthRequestBody,
+    },
+};
+
+// 비동기로 실행할 수 있는 크롤링 종류
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CrawlKind {
+    PpUserInfo,
+    PpAllPeriods,
+    PpLatest3,
+    KepcoThreeYear,
+    PpBatch,
+    PpCustomers,
+    PpLoadProfile,
+    PpUsageAggregates,
+    PpBillBreakdown,
+    PpDemandHistory,
+}
+
+// POST /crawling/jobs. kind 와 함께 각 크롤링의 기존 요청 body 를 그대로 받음
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CrawlJobRequest {
+    PpUserInfo(PpRequestBody),
+    PpAllPeriods(PpRequestBody),
+    PpLatest3(PpRequestBody),
+    KepcoThreeYear(PpRequestBody),
+    PpBatch(PpBatchRequestBody),
+    PpCustomers(PpAccountRequestBody),
+    PpLoadProfile(PpDateRangeRequestBody),
+    PpUsageAggregates(PpMonthRequestBody),
+    PpBillBreakdown(PpRequestBody),
+    PpDemandHistory(PpYearRequestBody),
+}
+
+impl CrawlJobRequest {
+    pub fn kind(&self) -> CrawlKind {
+        match self {
+            CrawlJobRequest::PpUserInfo(_) => CrawlKind::PpUserInfo,
+            CrawlJobRequest::PpAllPeriods(_) => CrawlKind::PpAllPeriods,
+            CrawlJobRequest::PpLatest3(_) => CrawlKind::PpLatest3,
+            CrawlJobRequest::KepcoThreeYear(_) => CrawlKind::KepcoThreeYear,
+            CrawlJobRequest::PpBatch(_) => CrawlKind::PpBatch,
+            CrawlJobRequest::PpCustomers(_) => CrawlKind::PpCustomers,
+            CrawlJobRequest::PpLoadProfile(_) => CrawlKind::PpLoadProfile,
+            CrawlJobRequest::PpUsageAggregates(_) => CrawlKind::PpUsageAggregates,
+            CrawlJobRequest::PpBillBreakdown(_) => CrawlKind::PpBillBreakdown,
+            CrawlJobRequest::PpDemandHistory(_) => CrawlKind::PpDemandHistory,
+        }
+    }
+
+    pub fn test_state(&self) -> bool {
+        match self {
+            CrawlJobRequest::PpUserInfo(params)
+            | CrawlJobRequest::PpAllPeriods(params)
+            | CrawlJobRequest::PpLatest3(params)
+            | CrawlJobRequest::KepcoThreeYear(params) => params.test_state(),
+            CrawlJobRequest::PpBatch(params) => params.test_state(),
+            CrawlJobRequest::PpCustomers(params) => params.test_state(),
+            CrawlJobRequest::PpLoadProfile(params) => params.test_state(),
+            CrawlJobRequest::PpUsageAggregates(params) => params.test_state(),
+            CrawlJobRequest::PpBillBreakdown(params) => params.test_state(),
+            CrawlJobRequest::PpDemandHistory(params) => params.test_state(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Succeeded | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
+}
+
+// GET /crawling/jobs/{id} 응답
+#[derive(Serialize, Debug, Clone)]
+pub struct CrawlJob {
+    pub id: Uuid,
+    pub kind: CrawlKind,
+    // job 을 등록한 클라이언트. 등록한 클라이언트와 admin 만 조회 / 취소 가능
+    pub owner: ClientIdentity,
+    pub status: JobStatus,
+    pub progress: Option<ProgressEvent>,
+    pub result: Option<Value>,
+    pub error: Option<Value>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CrawlJob {
+    pub fn new(kind: CrawlKind, owner: ClientIdentity) -> Self {
+        let now = Utc::now();
+
+        CrawlJob {
+            id: Uuid::new_v4(),
+            kind,
+            owner,
+            status: JobStatus::Queued,
+            progress: None,
+            result: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn visible_to(&self, client: &ClientIdentity) -> bool {
+        client.admin || self.owner.name == client.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::handler::pp::batch::PpBatchKind;
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    // 요청 body 가 kind 에 맞는 구조체로 parsing 되었는지 확인
+    type BodyCheck = fn(&CrawlJobRequest) -> bool;
+
+    fn account() -> Value {
+        json!({ "userId": "id", "userPw": "pw", "userNum": "0123456789" })
+    }
+
+    fn request(kind: &str, extra: Value) -> CrawlJobRequest {
+        parse(kind, extra).unwrap()
+    }
+
+    #[test]
+    fn job_request_keeps_the_flat_request_body() {
+        let request = request("pp_all_periods", json!({ "testMode": true }));
+
+        assert_eq!(request.kind(), CrawlKind::PpAllPeriods);
+        assert!(request.test_state());
+        assert!(serde_json::from_value::<CrawlJobRequest>(json!({ "kind": "unknown" })).is_err());
+    }
+
+    #[test]
+    fn batch_job_takes_the_batch_kind_as_batch_kind() {
+        let request = request(
+            "pp_batch",
+            json!({ "batchKind": "pp_latest3", "userNums": "all" }),
+        );
+
+        assert_eq!(request.kind(), CrawlKind::PpBatch);
+        let CrawlJobRequest::PpBatch(params) = request else {
+            panic!("expected a batch job");
+        };
+        assert_eq!(params.kind, PpBatchKind::PpLatest3);
+    }
+
+    fn parse(kind: &str, extra: Value) -> Result<CrawlJobRequest, serde_json::Error> {
+        let mut body = account();
+        body["kind"] = json!(kind);
+        if let (Some(body), Some(extra)) = (body.as_object_mut(), extra.as_object()) {
+            body.extend(extra.clone());
+        }
+        serde_json::from_value(body)
+    }
+
+    #[test]
+    fn job_request_body_fields_are_parsed_per_kind() {
+        let cases: [(&str, Value, BodyCheck); 5] = [
+            ("pp_customers", json!({}), |request| {
+                matches!(request, CrawlJobRequest::PpCustomers(params)
+                    if params.userId == "id" && params.userPw == "pw")
+            }),
+            (
+                "pp_load_profile",
+                json!({ "startDate": "2025-01-01", "endDate": "2025-01-07" }),
+                |request| {
+                    matches!(request, CrawlJobRequest::PpLoadProfile(params)
+                        if params.startDate == NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+                            && params.endDate == NaiveDate::from_ymd_opt(2025, 1, 7).unwrap()
+                            && params.account.userNum == "0123456789")
+                },
+            ),
+            (
+                "pp_usage_aggregates",
+                json!({ "year": 2025, "month": 3 }),
+                |request| {
+                    matches!(request, CrawlJobRequest::PpUsageAggregates(params)
+                        if params.year == 2025
+                            && params.month == 3
+                            && params.account.userNum == "0123456789")
+                },
+            ),
+            ("pp_bill_breakdown", json!({}), |request| {
+                matches!(request, CrawlJobRequest::PpBillBreakdown(params)
+                    if params.userNum == "0123456789")
+            }),
+            ("pp_demand_history", json!({ "year": 2024 }), |request| {
+                matches!(request, CrawlJobRequest::PpDemandHistory(params)
+                    if params.year == Some(2024) && params.account.userNum == "0123456789")
+            }),
+        ];
+
+        for (kind, extra, expected) in cases {
+            let request = parse(kind, extra).unwrap();
+            assert!(
+                expected(&request),
+                "{} body was not parsed: {:?}",
+                kind,
+                request
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_job_request_bodies_are_rejected() {
+        let cases = [
+            (
+                "pp_load_profile",
+                json!({ "startDate": "2025-13-01", "endDate": "2025-01-07" }),
+            ),
+            ("pp_load_profile", json!({ "startDate": "2025-01-01" })),
+            (
+                "pp_usage_aggregates",
+                json!({ "year": 2025, "month": "march" }),
+            ),
+            ("pp_usage_aggregates", json!({ "year": 2025 })),
+            ("pp_demand_history", json!({ "year": "2024" })),
+        ];
+
+        for (kind, extra) in cases {
+            assert!(
+                parse(kind, extra.clone()).is_err(),
+                "{} accepted {}",
+                kind,
+                extra
+            );
+        }
+
+        let mut without_user_num = account();
+        without_user_num["kind"] = json!("pp_bill_breakdown");
+        without_user_num.as_object_mut().unwrap().remove("userNum");
+        assert!(serde_json::from_value::<CrawlJobRequest>(without_user_num).is_err());
+    }
+}
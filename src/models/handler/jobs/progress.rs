@@ -0,0 +1,40 @@
+use serde_derive::Serialize;
+use serde_json::Value;
+
+// 크롤링 진행 이벤트. WebSocket 으로 그대로 직렬화되어 전달됨
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProgressEvent {
+    SessionCheckedOut,
+    LoggedIn,
+    CustomerSelected {
+        user_num: String,
+    },
+    RowsFound {
+        rows: usize,
+    },
+    PeriodParsed {
+        label: String,
+        current: usize,
+        total: usize,
+        rows: usize,
+    },
+    Finished {
+        rows: Option<usize>,
+    },
+    Failed {
+        error: Value,
+    },
+    Cancelled,
+}
+
+impl ProgressEvent {
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ProgressEvent::Finished { .. }
+                | ProgressEvent::Failed { .. }
+                | ProgressEvent::Cancelled
+        )
+    }
+}
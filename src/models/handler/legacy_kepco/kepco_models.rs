@@ -1,7 +1,10 @@
-use axum::{Json, http::StatusCode, response::IntoResponse};
 use chrono::NaiveDate;
 use serde_derive::Serialize;
 
+use crate::models::driver::sites::SitePage;
+
+pub const KEPCO_ON_MAIN: SitePage = SitePage::new("kepco_on", "main");
+
 // 한전온 요금 데이터
 #[derive(Serialize, Debug)]
 pub struct KepcoData {
@@ -15,19 +18,3 @@ pub struct KepcoData {
     pub payment_method: Option<String>,
     pub payment_date: Option<NaiveDate>,
 }
-
-#[derive(Serialize, Debug)]
-pub struct MetaResponseData {}
-
-#[derive(Serialize, Debug)]
-pub struct ThreeYearKepcoDataResponse {
-    pub data: Vec<KepcoData>,
-    pub meta: MetaResponseData,
-}
-
-impl IntoResponse for ThreeYearKepcoDataResponse {
-    fn into_response(self) -> axum::response::Response {
-        let body = Json(self);
-        (StatusCode::OK, body).into_response()
-    }
-}
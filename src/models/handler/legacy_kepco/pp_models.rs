@@ -1,4 +1,3 @@
-use axum::{Json, http::StatusCode, response::IntoResponse};
 use chrono::NaiveDate;
 use serde_derive::Serialize;
 
@@ -9,19 +8,3 @@ pub struct PpAllPeriodsPaidData {
     pub usage: f64,
     pub paid: i64,
 }
-
-#[derive(Serialize, Debug)]
-pub struct MetaResponseData {}
-
-#[derive(Serialize, Debug)]
-pub struct PpAllPeriodsPaidDataResponse {
-    pub data: Vec<PpAllPeriodsPaidData>,
-    pub meta: MetaResponseData,
-}
-
-impl IntoResponse for PpAllPeriodsPaidData {
-    fn into_response(self) -> axum::response::Response {
-        let body = Json(self);
-        (StatusCode::OK, body).into_response()
-    }
-}
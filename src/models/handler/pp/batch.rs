@@ -0,0 +1,50 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+// 배치로 실행할 수 있는 파워플래너 크롤링 종류
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PpBatchKind {
+    PpUserInfo,
+    PpAllPeriods,
+    PpLatest3,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AllCustomers {
+    All,
+}
+
+// 고객번호 목록 또는 "all"
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum CustomerNumbers {
+    List(Vec<String>),
+    All(AllCustomers),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PpBatchRequestBody {
+    // job 으로 등록할 때는 kind 가 job 종류이므로 batchKind 로 받음
+    #[serde(alias = "batchKind")]
+    pub kind: PpBatchKind,
+    pub userId: String,
+    pub userPw: String,
+    pub userNums: CustomerNumbers,
+    pub testMode: Option<bool>,
+}
+
+impl PpBatchRequestBody {
+    pub fn test_state(&self) -> bool {
+        self.testMode.unwrap_or(false)
+    }
+}
+
+// 고객번호별 결과. 실패한 고객번호는 error 만 채워짐
+#[derive(Serialize, Debug)]
+pub struct PpBatchResult {
+    pub user_num: String,
+    pub data: Option<Value>,
+    pub error: Option<Value>,
+}
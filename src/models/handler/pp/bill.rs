@@ -0,0 +1,54 @@
+use chrono::NaiveDate;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::models::handler::pp::{commons::PpRequestBody, load_profile::LoadBand};
+
+// 시간대별 전력량요금. 시간대 구분이 없는 요금제는 band 가 None
+#[derive(Serialize, Debug, Clone)]
+pub struct EnergyCharge {
+    pub band: Option<LoadBand>,
+    pub amount: i64,
+}
+
+// 분류되지 않은 청구 항목 또는 할인 항목
+#[derive(Serialize, Debug, Clone)]
+pub struct BillItem {
+    pub label: String,
+    pub amount: i64,
+}
+
+// 월별 청구서 상세 내역
+#[derive(Serialize, Debug, Default)]
+pub struct BillBreakdown {
+    pub claim_date: NaiveDate,
+    pub usage: f64,
+    pub basic_charge: i64,
+    pub energy_charges: Vec<EnergyCharge>,
+    pub climate_environment_charge: i64,
+    pub fuel_cost_adjustment: i64,
+    pub vat: i64,
+    pub power_industry_fund: i64,
+    pub tv_license_fee: i64,
+    pub discounts: Vec<BillItem>,
+    pub other_items: Vec<BillItem>,
+    pub total: i64,
+}
+
+// 범위 조회 시 최대 개월 수
+pub const BILL_PDF_MAX_MONTHS: usize = 24;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PpBillPdfRequestBody {
+    #[serde(flatten)]
+    pub account: PpRequestBody,
+    // YYYY-MM
+    pub claimMonth: String,
+    // 지정하면 claimMonth ~ endMonth 를 zip 으로 반환
+    pub endMonth: Option<String>,
+}
+
+impl PpBillPdfRequestBody {
+    pub fn test_state(&self) -> bool {
+        self.account.test_state()
+    }
+}
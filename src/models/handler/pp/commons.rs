@@ -1,9 +1,13 @@
 use serde_derive::Deserialize;
 
-pub const PP_URL: &str = "https://pp.kepco.co.kr";
-pub const HOME_URL: &str = "https://pp.kepco.co.kr/rm/rm0101.do?menu_id=O010101";
-pub const USER_INFO_URL: &str = "https://pp.kepco.co.kr/mb/mb0101.do?menu_id=O010601";
-pub const USER_SELECT_CHARGE_URL: &str = "https://pp.kepco.co.kr/pf/pf0101_1.do?menu_id=O010501";
+use crate::models::driver::sites::SitePage;
+
+// 주소는 sites.toml 에서 관리
+pub const PP_SITE: &str = "pp";
+pub const PP_INTRO: SitePage = SitePage::new(PP_SITE, "intro");
+pub const PP_HOME: SitePage = SitePage::new(PP_SITE, "home");
+pub const PP_USER_INFO: SitePage = SitePage::new(PP_SITE, "user_info");
+pub const PP_SELECT_CHARGE: SitePage = SitePage::new(PP_SITE, "select_charge");
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct PpRequestBody {
@@ -0,0 +1,23 @@
+use serde_derive::{Deserialize, Serialize};
+
+// 고객번호 선택 없이 로그인만 필요한 요청
+#[derive(Deserialize, Debug, Clone)]
+pub struct PpAccountRequestBody {
+    pub userId: String,
+    pub userPw: String,
+    pub testMode: Option<bool>,
+}
+
+impl PpAccountRequestBody {
+    pub fn test_state(&self) -> bool {
+        self.testMode.unwrap_or(false)
+    }
+}
+
+// 로그인 후 고객번호 목록의 항목
+#[derive(Serialize, Debug, Clone)]
+pub struct PpCustomer {
+    pub user_num: String,
+    pub name: Option<String>,
+    pub address: Option<String>,
+}
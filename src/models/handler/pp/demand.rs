@@ -0,0 +1,44 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::models::{
+    driver::sites::SitePage,
+    handler::pp::commons::{PP_SITE, PpRequestBody},
+};
+
+pub const PP_DEMAND_HISTORY: SitePage = SitePage::new(PP_SITE, "demand_history");
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PpYearRequestBody {
+    #[serde(flatten)]
+    pub account: PpRequestBody,
+    // 미지정 시 올해
+    pub year: Option<i32>,
+}
+
+impl PpYearRequestBody {
+    pub fn test_state(&self) -> bool {
+        self.account.test_state()
+    }
+}
+
+// 월별 최대수요전력 및 역률
+#[derive(Serialize, Debug, Clone)]
+pub struct DemandRecord {
+    pub month: NaiveDate,
+    pub peak_kw: f64,
+    pub peak_at: Option<NaiveDateTime>,
+    pub billing_demand_kw: Option<f64>,
+    pub lagging_power_factor: Option<f64>,
+    pub leading_power_factor: Option<f64>,
+    // 최대수요전력이 계약전력을 초과한 달
+    pub exceeds_contract_power: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PpDemandHistory {
+    pub user_num: String,
+    // UserInfo.contract_power 와 같은 값
+    pub contract_power: f64,
+    pub records: Vec<DemandRecord>,
+}
@@ -0,0 +1,170 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike, Weekday};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::models::{
+    driver::sites::SitePage,
+    handler::pp::commons::{PP_SITE, PpRequestBody},
+};
+
+pub const PP_REALTIME_USAGE: SitePage = SitePage::new(PP_SITE, "realtime_usage");
+// 15분 사용량은 한 번에 하루씩만 조회 가능
+pub const LOAD_PROFILE_MAX_DAYS_PER_QUERY: i64 = 1;
+// 한 요청에서 허용하는 최대 조회 기간
+pub const LOAD_PROFILE_MAX_RANGE_DAYS: i64 = 31;
+pub const LOAD_PROFILE_INTERVAL_MINUTES: i64 = 15;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PpDateRangeRequestBody {
+    #[serde(flatten)]
+    pub account: PpRequestBody,
+    pub startDate: NaiveDate,
+    pub endDate: NaiveDate,
+}
+
+impl PpDateRangeRequestBody {
+    pub fn test_state(&self) -> bool {
+        self.account.test_state()
+    }
+}
+
+// 계시별 부하 구분
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadBand {
+    OffPeak,
+    MidPeak,
+    Peak,
+}
+
+// 양력 고정 공휴일 (월, 일)
+const FIXED_HOLIDAYS: [(u32, u32); 8] = [
+    (1, 1),
+    (3, 1),
+    (5, 5),
+    (6, 6),
+    (8, 15),
+    (10, 3),
+    (10, 9),
+    (12, 25),
+];
+
+// 음력 공휴일 (설날·추석 연휴, 부처님오신날). 연도가 지나면 추가 필요
+const LUNAR_HOLIDAYS: [(i32, u32, u32); 35] = [
+    (2023, 1, 21),
+    (2023, 1, 22),
+    (2023, 1, 23),
+    (2023, 5, 27),
+    (2023, 9, 28),
+    (2023, 9, 29),
+    (2023, 9, 30),
+    (2024, 2, 9),
+    (2024, 2, 10),
+    (2024, 2, 11),
+    (2024, 5, 15),
+    (2024, 9, 16),
+    (2024, 9, 17),
+    (2024, 9, 18),
+    (2025, 1, 28),
+    (2025, 1, 29),
+    (2025, 1, 30),
+    (2025, 5, 5),
+    (2025, 10, 5),
+    (2025, 10, 6),
+    (2025, 10, 7),
+    (2026, 2, 16),
+    (2026, 2, 17),
+    (2026, 2, 18),
+    (2026, 5, 24),
+    (2026, 9, 24),
+    (2026, 9, 25),
+    (2026, 9, 26),
+    (2027, 2, 6),
+    (2027, 2, 7),
+    (2027, 2, 8),
+    (2027, 5, 13),
+    (2027, 9, 14),
+    (2027, 9, 15),
+    (2027, 9, 16),
+];
+
+// 계시별 요금의 공휴일. 대체공휴일, 선거일, 임시공휴일은 반영하지 않음
+pub fn is_holiday(date: NaiveDate) -> bool {
+    FIXED_HOLIDAYS.contains(&(date.month(), date.day()))
+        || LUNAR_HOLIDAYS.contains(&(date.year(), date.month(), date.day()))
+}
+
+impl LoadBand {
+    // 한전 계시별 요금 시간대 (일요일·공휴일은 전부 경부하, 토요일은 최대부하를 중간부하로 적용)
+    pub fn of(timestamp: NaiveDateTime) -> LoadBand {
+        let hour = timestamp.hour();
+        if !(8..22).contains(&hour) {
+            return LoadBand::OffPeak;
+        }
+
+        let weekday = timestamp.weekday();
+        if weekday == Weekday::Sun || is_holiday(timestamp.date()) {
+            return LoadBand::OffPeak;
+        }
+
+        let on_peak = match timestamp.month() {
+            // 겨울철 (11 ~ 2월)
+            11 | 12 | 1 | 2 => matches!(hour, 9..=11 | 16..=18),
+            // 여름철, 봄·가을철
+            _ => matches!(hour, 11 | 13..=17),
+        };
+
+        match (on_peak, weekday) {
+            (true, Weekday::Sat) => LoadBand::MidPeak,
+            (true, _) => LoadBand::Peak,
+            (false, _) => LoadBand::MidPeak,
+        }
+    }
+}
+
+// 15분 구간 사용량. timestamp 는 구간 시작 시각
+#[derive(Serialize, Debug, Clone)]
+pub struct LoadProfileRow {
+    pub timestamp: NaiveDateTime,
+    pub kwh: f64,
+    pub band: LoadBand,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .and_then(|date| date.and_hms_opt(h, 0, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn weekday_bands_follow_the_season() {
+        // 2024-07-10 수요일 (여름철)
+        assert_eq!(LoadBand::of(at(2024, 7, 10, 7)), LoadBand::OffPeak);
+        assert_eq!(LoadBand::of(at(2024, 7, 10, 10)), LoadBand::MidPeak);
+        assert_eq!(LoadBand::of(at(2024, 7, 10, 11)), LoadBand::Peak);
+        assert_eq!(LoadBand::of(at(2024, 7, 10, 12)), LoadBand::MidPeak);
+        assert_eq!(LoadBand::of(at(2024, 7, 10, 15)), LoadBand::Peak);
+        assert_eq!(LoadBand::of(at(2024, 7, 10, 22)), LoadBand::OffPeak);
+
+        // 2024-12-11 수요일 (겨울철)
+        assert_eq!(LoadBand::of(at(2024, 12, 11, 9)), LoadBand::Peak);
+        assert_eq!(LoadBand::of(at(2024, 12, 11, 13)), LoadBand::MidPeak);
+        assert_eq!(LoadBand::of(at(2024, 12, 11, 17)), LoadBand::Peak);
+    }
+
+    #[test]
+    fn weekends_and_holidays_have_no_peak() {
+        // 토요일은 최대부하 시간대도 중간부하
+        assert_eq!(LoadBand::of(at(2024, 7, 13, 15)), LoadBand::MidPeak);
+        // 일요일
+        assert_eq!(LoadBand::of(at(2024, 7, 14, 15)), LoadBand::OffPeak);
+        // 광복절 (목요일), 추석 연휴 (월·화)
+        assert_eq!(LoadBand::of(at(2024, 8, 15, 15)), LoadBand::OffPeak);
+        assert_eq!(LoadBand::of(at(2024, 9, 16, 11)), LoadBand::OffPeak);
+        assert_eq!(LoadBand::of(at(2024, 9, 17, 11)), LoadBand::OffPeak);
+        assert_eq!(LoadBand::of(at(2024, 9, 19, 11)), LoadBand::Peak);
+    }
+}
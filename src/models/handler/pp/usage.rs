@@ -0,0 +1,43 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::models::{
+    driver::sites::SitePage,
+    handler::pp::commons::{PP_SITE, PpRequestBody},
+};
+
+pub const PP_DAILY_USAGE: SitePage = SitePage::new(PP_SITE, "daily_usage");
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PpMonthRequestBody {
+    #[serde(flatten)]
+    pub account: PpRequestBody,
+    pub year: i32,
+    pub month: u32,
+}
+
+impl PpMonthRequestBody {
+    pub fn test_state(&self) -> bool {
+        self.account.test_state()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DailyUsage {
+    pub date: NaiveDate,
+    pub kwh: f64,
+}
+
+// timestamp 는 구간 시작 시각
+#[derive(Serialize, Debug, Clone)]
+pub struct HourlyUsage {
+    pub timestamp: NaiveDateTime,
+    pub kwh: f64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PpUsageAggregates {
+    pub user_num: String,
+    pub daily: Vec<DailyUsage>,
+    pub hourly: Vec<HourlyUsage>,
+}
@@ -65,3 +65,12 @@ pub struct UserInfo {
     pub inspection_day: i16,
     pub instrument_number: String,
 }
+
+impl UserInfo {
+    // 저압 요금제는 최대수요전력/역률 과금 대상이 아님
+    pub fn is_low_voltage(&self) -> bool {
+        purpose_vec()
+            .into_iter()
+            .any(|(name, id)| id == self.purpose_id && name.starts_with("저압"))
+    }
+}
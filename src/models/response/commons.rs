@@ -1,6 +1,9 @@
 use axum::{Json, http::StatusCode, response::IntoResponse, response::Response};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use serde_json::Value;
+
+use crate::models::error::response_errors_def::ErrorResponseCode;
 
 #[derive(Serialize)]
 pub struct GenericResponseStruct<D, M>
@@ -39,3 +42,7 @@ pub fn basic_response<D: Serialize>(data: D, run_time: tokio::time::Duration) ->
     }
     .into_response()
 }
+
+pub fn to_json_value<D: Serialize>(data: D) -> Result<Value, ErrorResponseCode> {
+    serde_json::to_value(data).map_err(|e| ErrorResponseCode::SERIALIZE_RESULT.caused_by(e))
+}
@@ -0,0 +1,53 @@
+use chrono::NaiveDate;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::models::tariff::rates::BandUsage;
+
+// 크롤링한 월별 사용량
+#[derive(Deserialize, Debug, Clone)]
+pub struct MonthlyUsage {
+    pub claim_month: NaiveDate,
+    pub usage_kwh: Option<f64>,
+    pub band_usage: Option<BandUsage>,
+    pub billing_demand_kw: Option<f64>,
+}
+
+// get_user_info_handler 의 contract_type_id / purpose_id / contract_power 와 월별 사용량
+#[derive(Deserialize, Debug, Clone)]
+pub struct TariffCompareRequestBody {
+    pub contract_type_id: i16,
+    pub purpose_id: i16,
+    pub contract_power: f64,
+    pub months: Vec<MonthlyUsage>,
+    // 일반용 <-> 산업용 요금제도 비교. 용도는 업종으로 정해지므로 전환 가능한 경우에만 지정
+    #[serde(default)]
+    pub include_other_use: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PlanTotal {
+    pub contract_type_id: i16,
+    pub purpose_id: i16,
+    pub plan: &'static str,
+    pub total: i64,
+    // 현재 요금제 대비 절감액 (양수면 절감)
+    pub savings: i64,
+}
+
+// 선택 가능하지만 계산하지 못한 요금제
+#[derive(Serialize, Debug, Clone)]
+pub struct SkippedPlan {
+    pub contract_type_id: i16,
+    pub purpose_id: i16,
+    pub plan: &'static str,
+    pub reason: &'static str,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TariffComparison {
+    pub months: usize,
+    pub current: PlanTotal,
+    // 절감액 순 정렬
+    pub alternatives: Vec<PlanTotal>,
+    pub skipped: Vec<SkippedPlan>,
+}
@@ -0,0 +1,40 @@
+use serde_derive::{Deserialize, Serialize};
+
+use crate::models::tariff::rates::{TariffBill, TariffInput};
+
+// 실제 청구액과 계산값의 차이가 이 비율(또는 최소 금액)을 넘으면 이상으로 판단
+pub const ANOMALY_TOLERANCE_RATE: f64 = 0.01;
+pub const ANOMALY_TOLERANCE_MIN: i64 = 1000;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TariffEstimateRequestBody {
+    #[serde(flatten)]
+    pub input: TariffInput,
+    // 크롤링한 실제 청구액 (PpAllPeriodsPaidData.paid)
+    pub paid: Option<i64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TariffEstimate {
+    pub expected: TariffBill,
+    pub paid: Option<i64>,
+    // paid - expected.total
+    pub difference: Option<i64>,
+    pub anomaly: bool,
+}
+
+impl TariffEstimate {
+    pub fn new(expected: TariffBill, paid: Option<i64>) -> Self {
+        let difference = paid.map(|paid| paid - expected.total);
+        let tolerance =
+            ((expected.total as f64 * ANOMALY_TOLERANCE_RATE) as i64).max(ANOMALY_TOLERANCE_MIN);
+        let anomaly = difference.is_some_and(|difference| difference.abs() > tolerance);
+
+        TariffEstimate {
+            expected,
+            paid,
+            difference,
+            anomaly,
+        }
+    }
+}
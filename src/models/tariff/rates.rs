@@ -0,0 +1,390 @@
+use chrono::{Datelike, NaiveDate};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::models::handler::pp::load_profile::LoadBand;
+
+// 계절 구분 (여름철 6~8월, 봄·가을철 3~5월·9~10월, 겨울철 11~2월)
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Season {
+    Summer,
+    SpringFall,
+    Winter,
+}
+
+impl Season {
+    pub fn of(month: NaiveDate) -> Season {
+        match month.month() {
+            6..=8 => Season::Summer,
+            3..=5 | 9 | 10 => Season::SpringFall,
+            _ => Season::Winter,
+        }
+    }
+}
+
+// 시간대별 전력량요금 (원/kWh)
+#[derive(Debug, Clone, Copy)]
+pub struct BandRates {
+    pub off_peak: f64,
+    pub mid_peak: f64,
+    pub peak: f64,
+}
+
+impl BandRates {
+    pub fn rate(&self, band: LoadBand) -> f64 {
+        match band {
+            LoadBand::OffPeak => self.off_peak,
+            LoadBand::MidPeak => self.mid_peak,
+            LoadBand::Peak => self.peak,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SeasonRates<T> {
+    pub summer: T,
+    pub spring_fall: T,
+    pub winter: T,
+}
+
+impl<T: Copy> SeasonRates<T> {
+    pub fn of(&self, season: Season) -> T {
+        match season {
+            Season::Summer => self.summer,
+            Season::SpringFall => self.spring_fall,
+            Season::Winter => self.winter,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum EnergyRates {
+    // 계절별 단일 요금 (저압)
+    Seasonal(SeasonRates<f64>),
+    // 계시별 요금 (고압)
+    TimeOfUse(SeasonRates<BandRates>),
+}
+
+// 계약종별(contract_vec id) + 선택요금(purpose_vec id) 별 요금
+#[derive(Debug, Clone, Copy)]
+pub struct PlanRates {
+    pub contract_type_id: i16,
+    pub purpose_id: i16,
+    pub name: &'static str,
+    // 기본요금 (원/kW)
+    pub basic_charge_per_kw: f64,
+    pub energy: EnergyRates,
+}
+
+// 시행일 기준 요금표
+#[derive(Debug, Clone)]
+pub struct RateTable {
+    pub version: &'static str,
+    pub effective_from: NaiveDate,
+    // 기후환경요금 (원/kWh)
+    pub climate_environment_per_kwh: f64,
+    pub vat_rate: f64,
+    pub power_industry_fund_rate: f64,
+    pub plans: Vec<PlanRates>,
+}
+
+impl RateTable {
+    pub fn plan(&self, contract_type_id: i16, purpose_id: i16) -> Option<&PlanRates> {
+        self.plans
+            .iter()
+            .find(|plan| plan.contract_type_id == contract_type_id && plan.purpose_id == purpose_id)
+    }
+}
+
+const fn seasonal(summer: f64, spring_fall: f64, winter: f64) -> EnergyRates {
+    EnergyRates::Seasonal(SeasonRates {
+        summer,
+        spring_fall,
+        winter,
+    })
+}
+
+const fn band(off_peak: f64, mid_peak: f64, peak: f64) -> BandRates {
+    BandRates {
+        off_peak,
+        mid_peak,
+        peak,
+    }
+}
+
+const fn time_of_use(summer: BandRates, spring_fall: BandRates, winter: BandRates) -> EnergyRates {
+    EnergyRates::TimeOfUse(SeasonRates {
+        summer,
+        spring_fall,
+        winter,
+    })
+}
+
+// 전력량요금 일괄 조정 (요금 개정 시 종별 단위로 인상/인하)
+fn shift_energy(plan: PlanRates, delta: f64) -> PlanRates {
+    let shift = |rates: BandRates| {
+        band(
+            rates.off_peak + delta,
+            rates.mid_peak + delta,
+            rates.peak + delta,
+        )
+    };
+
+    let energy = match plan.energy {
+        EnergyRates::Seasonal(rates) => seasonal(
+            rates.summer + delta,
+            rates.spring_fall + delta,
+            rates.winter + delta,
+        ),
+        EnergyRates::TimeOfUse(rates) => time_of_use(
+            shift(rates.summer),
+            shift(rates.spring_fall),
+            shift(rates.winter),
+        ),
+    };
+
+    PlanRates { energy, ..plan }
+}
+
+fn plans_2024_10_24() -> Vec<PlanRates> {
+    vec![
+        PlanRates {
+            contract_type_id: 13,
+            purpose_id: 23,
+            name: "일반용(갑)I 저압",
+            basic_charge_per_kw: 6160.0,
+            energy: seasonal(135.0, 90.4, 120.2),
+        },
+        PlanRates {
+            contract_type_id: 6,
+            purpose_id: 23,
+            name: "산업용(갑)I 저압",
+            basic_charge_per_kw: 5550.0,
+            energy: seasonal(99.5, 76.4, 97.6),
+        },
+        PlanRates {
+            contract_type_id: 15,
+            purpose_id: 11,
+            name: "일반용(을) 고압A 선택I",
+            basic_charge_per_kw: 7170.0,
+            energy: time_of_use(
+                band(118.6, 171.1, 251.1),
+                band(118.6, 140.7, 171.8),
+                band(125.6, 171.1, 226.0),
+            ),
+        },
+        PlanRates {
+            contract_type_id: 15,
+            purpose_id: 12,
+            name: "일반용(을) 고압A 선택II",
+            basic_charge_per_kw: 8230.0,
+            energy: time_of_use(
+                band(113.2, 165.7, 245.7),
+                band(113.2, 135.3, 166.4),
+                band(120.2, 165.7, 220.6),
+            ),
+        },
+        PlanRates {
+            contract_type_id: 8,
+            purpose_id: 11,
+            name: "산업용(을) 고압A 선택I",
+            basic_charge_per_kw: 7220.0,
+            energy: time_of_use(
+                band(110.1, 162.6, 245.1),
+                band(110.1, 134.2, 165.6),
+                band(117.1, 162.6, 219.4),
+            ),
+        },
+        PlanRates {
+            contract_type_id: 8,
+            purpose_id: 12,
+            name: "산업용(을) 고압A 선택II",
+            basic_charge_per_kw: 8320.0,
+            energy: time_of_use(
+                band(104.6, 157.2, 239.7),
+                band(104.6, 128.8, 160.2),
+                band(111.6, 157.2, 214.0),
+            ),
+        },
+        PlanRates {
+            contract_type_id: 8,
+            purpose_id: 13,
+            name: "산업용(을) 고압A 선택III",
+            basic_charge_per_kw: 9810.0,
+            energy: time_of_use(
+                band(103.3, 155.9, 238.4),
+                band(103.3, 127.5, 158.9),
+                band(110.3, 155.9, 212.7),
+            ),
+        },
+        PlanRates {
+            contract_type_id: 8,
+            purpose_id: 15,
+            name: "산업용(을) 고압B 선택I",
+            basic_charge_per_kw: 6630.0,
+            energy: time_of_use(
+                band(109.4, 161.5, 241.0),
+                band(109.4, 131.8, 162.6),
+                band(116.4, 161.5, 216.4),
+            ),
+        },
+        PlanRates {
+            contract_type_id: 8,
+            purpose_id: 16,
+            name: "산업용(을) 고압B 선택II",
+            basic_charge_per_kw: 7380.0,
+            energy: time_of_use(
+                band(107.7, 159.8, 239.3),
+                band(107.7, 130.1, 160.9),
+                band(114.7, 159.8, 214.7),
+            ),
+        },
+        PlanRates {
+            contract_type_id: 8,
+            purpose_id: 17,
+            name: "산업용(을) 고압B 선택III",
+            basic_charge_per_kw: 7240.0,
+            energy: time_of_use(
+                band(107.2, 159.3, 238.8),
+                band(107.2, 129.6, 160.4),
+                band(114.2, 159.3, 214.2),
+            ),
+        },
+    ]
+}
+
+// 2024-10-24 개정 전에는 산업용(을) 전력량요금이 16.1원/kWh 낮음
+fn plans_2023_11_09() -> Vec<PlanRates> {
+    plans_2024_10_24()
+        .into_iter()
+        .map(|plan| match plan.contract_type_id {
+            8 => shift_energy(plan, -16.1),
+            _ => plan,
+        })
+        .collect()
+}
+
+// 요금표 상수용 날짜. 잘못된 날짜면 컴파일 에러
+const fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => date,
+        None => panic!("invalid rate table date"),
+    }
+}
+
+// 전력산업기반기금 부담률. 2024-07-01 3.7% -> 3.2%, 2025-07-01 -> 2.7%
+const FUND_RATE_2023: f64 = 0.037;
+const FUND_RATE_2024_07: f64 = 0.032;
+const FUND_RATE_2025_07: f64 = 0.027;
+
+// 요금 개정일 또는 기금 부담률 변경일마다 하나씩. 시행일 오름차순
+pub fn rate_tables() -> Vec<RateTable> {
+    vec![
+        RateTable {
+            version: "2023-11-09",
+            effective_from: ymd(2023, 11, 9),
+            climate_environment_per_kwh: 9.0,
+            vat_rate: 0.1,
+            power_industry_fund_rate: FUND_RATE_2023,
+            plans: plans_2023_11_09(),
+        },
+        RateTable {
+            version: "2024-07-01",
+            effective_from: ymd(2024, 7, 1),
+            climate_environment_per_kwh: 9.0,
+            vat_rate: 0.1,
+            power_industry_fund_rate: FUND_RATE_2024_07,
+            plans: plans_2023_11_09(),
+        },
+        RateTable {
+            version: "2024-10-24",
+            effective_from: ymd(2024, 10, 24),
+            climate_environment_per_kwh: 9.0,
+            vat_rate: 0.1,
+            power_industry_fund_rate: FUND_RATE_2024_07,
+            plans: plans_2024_10_24(),
+        },
+        RateTable {
+            version: "2025-07-01",
+            effective_from: ymd(2025, 7, 1),
+            climate_environment_per_kwh: 9.0,
+            vat_rate: 0.1,
+            power_industry_fund_rate: FUND_RATE_2025_07,
+            plans: plans_2024_10_24(),
+        },
+    ]
+}
+
+// 분기별 연료비조정단가 (원/kWh). 분기 시작일 오름차순, 새 분기 발표 시 추가
+pub const FUEL_COST_ADJUSTMENTS: [(NaiveDate, f64); 12] = [
+    (ymd(2023, 1, 1), 5.0),
+    (ymd(2023, 4, 1), 5.0),
+    (ymd(2023, 7, 1), 5.0),
+    (ymd(2023, 10, 1), 5.0),
+    (ymd(2024, 1, 1), 5.0),
+    (ymd(2024, 4, 1), 5.0),
+    (ymd(2024, 7, 1), 5.0),
+    (ymd(2024, 10, 1), 5.0),
+    (ymd(2025, 1, 1), 5.0),
+    (ymd(2025, 4, 1), 5.0),
+    (ymd(2025, 7, 1), 5.0),
+    (ymd(2025, 10, 1), 5.0),
+];
+
+// 청구 월에 적용되는 연료비조정단가. 마지막 분기 이후는 마지막 값을 유지
+pub fn fuel_cost_adjustment_per_kwh(claim_month: NaiveDate) -> Option<f64> {
+    FUEL_COST_ADJUSTMENTS
+        .iter()
+        .rev()
+        .find(|(effective_from, _)| *effective_from <= claim_month)
+        .map(|(_, per_kwh)| *per_kwh)
+}
+
+// 시간대별 사용량 (kWh)
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default)]
+pub struct BandUsage {
+    pub off_peak: f64,
+    pub mid_peak: f64,
+    pub peak: f64,
+}
+
+impl BandUsage {
+    pub fn total(&self) -> f64 {
+        self.off_peak + self.mid_peak + self.peak
+    }
+}
+
+// 요금 계산 입력
+#[derive(Deserialize, Debug, Clone)]
+pub struct TariffInput {
+    pub contract_type_id: i16,
+    pub purpose_id: i16,
+    // 계약전력 (kW)
+    pub contract_power: f64,
+    // 요금적용전력 (kW). 없으면 계약전력으로 계산
+    pub billing_demand_kw: Option<f64>,
+    // 청구 월. 요금표 버전과 계절 선택에 사용
+    pub claim_month: NaiveDate,
+    // 월 사용량 (kWh). band_usage 가 있으면 그 합계를 사용
+    pub usage_kwh: Option<f64>,
+    pub band_usage: Option<BandUsage>,
+    // 지정 시 해당 버전의 요금표 사용
+    pub rate_version: Option<String>,
+}
+
+// 계산된 청구 금액 (원)
+#[derive(Serialize, Debug, Clone)]
+pub struct TariffBill {
+    pub rate_version: &'static str,
+    pub plan: &'static str,
+    pub season: Season,
+    pub usage_kwh: f64,
+    pub basic_charge: i64,
+    pub energy_charge: i64,
+    pub climate_environment_charge: i64,
+    pub fuel_cost_adjustment: i64,
+    pub electricity_charge: i64,
+    pub vat: i64,
+    pub power_industry_fund: i64,
+    pub total: i64,
+}
@@ -0,0 +1,16 @@
+use crate::utils::{
+    artifacts::ArtifactStore, auth::ApiKeyStore, canary::CanaryRunner, drivers::SessionPool,
+    jobs::JobStore, supervisor::DriverSupervisor,
+};
+use std::sync::Arc;
+
+// 핸들러들이 공유하는 상태
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: Arc<SessionPool>,
+    pub driver: Arc<DriverSupervisor>,
+    pub jobs: Arc<JobStore>,
+    pub artifacts: Arc<ArtifactStore>,
+    pub canary: Arc<CanaryRunner>,
+    pub clients: Arc<ApiKeyStore>,
+}
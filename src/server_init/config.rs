@@ -0,0 +1,499 @@
+use anyhow::{Result, anyhow};
+use clap::Parser;
+use serde_derive::Deserialize;
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::models::auth::api_keys::{ApiKeyConfig, ApiKeyFile, ClientLimits};
+use crate::models::driver::{
+    artifacts::ArtifactConfig,
+    canary::{CanaryConfig, DEFAULT_CANARY_INTERVAL_SECS},
+    chromes::BrowserConfig,
+    pool::PoolConfig,
+    selectors::SelectorConfig,
+    sites::SiteConfig,
+};
+use crate::models::handler::pp::commons::PpRequestBody;
+
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+const DEFAULT_LISTEN_ADDRESS: &str = "[::]:30737";
+const DEFAULT_DRIVER_PORT: u16 = 4450;
+const DEFAULT_PAGE_LOAD_TIMEOUT_SECS: u64 = 60;
+const DEFAULT_ARTIFACTS_DIR: &str = "artifacts";
+
+// 설정 항목. 우선순위는 CLI flag > 환경변수 > 설정 파일 > 기본값
+#[derive(Parser, Deserialize, Debug, Default, Clone)]
+#[command(version, about = "KEPCO crawling API server")]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigValues {
+    /// 설정 파일 경로 (TOML). 기본값 config.toml, 없으면 무시
+    #[arg(long, env = "CONFIG_PATH")]
+    #[serde(skip)]
+    pub config: Option<PathBuf>,
+
+    #[arg(long, env = "APP_NAME_VERSION")]
+    pub app_name_version: Option<String>,
+
+    /// 서버 주소. 기본값 [::]:30737
+    #[arg(long, env = "LISTEN_ADDRESS")]
+    pub listen_address: Option<String>,
+
+    #[arg(long, env = "CHROME_DRIVER_PATH")]
+    pub driver_path: Option<String>,
+
+    /// 기본값 4450
+    #[arg(long, env = "CHROME_DRIVER_PORT")]
+    pub driver_port: Option<u16>,
+
+    #[arg(long, env = "CHROME_BINARY_PATH")]
+    pub browser_binary: Option<String>,
+
+    /// testMode 가 아닌 요청에 사용. 없으면 browser_binary
+    #[arg(long, env = "CHROME_BINARY_PATH_TEST")]
+    pub browser_binary_test: Option<String>,
+
+    /// 컨테이너에서 root 로 실행할 때 필요
+    #[arg(long, env = "CHROME_NO_SANDBOX")]
+    pub browser_no_sandbox: Option<bool>,
+
+    /// 기본값 60
+    #[arg(long, env = "PAGE_LOAD_TIMEOUT_SECS")]
+    pub page_load_timeout_secs: Option<u64>,
+
+    #[arg(long, env = "POOL_MIN_SIZE")]
+    pub pool_min_size: Option<usize>,
+
+    #[arg(long, env = "POOL_MAX_SIZE")]
+    pub pool_max_size: Option<usize>,
+
+    #[arg(long, env = "POOL_CHECKOUT_TIMEOUT_SECS")]
+    pub pool_checkout_timeout_secs: Option<u64>,
+
+    #[arg(long, env = "POOL_HEALTH_CHECK_INTERVAL_SECS")]
+    pub pool_health_check_interval_secs: Option<u64>,
+
+    /// 기본값 artifacts
+    #[arg(long, env = "ARTIFACTS_DIR")]
+    pub artifacts_dir: Option<PathBuf>,
+
+    /// API 키 파일 경로 (TOML, [clients.<name>])
+    #[arg(long, env = "API_KEYS_PATH")]
+    pub api_keys_path: Option<PathBuf>,
+
+    /// "name:key,name:key". 같은 이름이 파일에도 있으면 이 값이 우선
+    #[arg(long, env = "API_KEYS", hide_env_values = true)]
+    pub api_keys: Option<String>,
+
+    /// 클라이언트별 기본 동시 크롤링 수. 기본값 2
+    #[arg(long, env = "CLIENT_MAX_CONCURRENT")]
+    pub client_max_concurrent: Option<usize>,
+
+    /// 클라이언트별 기본 일일 크롤링 횟수. 배치는 고객번호 수만큼 셈. 기본값 1000
+    #[arg(long, env = "CLIENT_DAILY_QUOTA")]
+    pub client_daily_quota: Option<u64>,
+
+    /// selector 파일 경로. 기본값 selectors.toml, 없으면 빌드에 포함된 값 사용
+    #[arg(long, env = "SELECTORS_PATH")]
+    pub selectors_path: Option<PathBuf>,
+
+    /// selector 파일 변경 확인 주기. 기본값 10
+    #[arg(long, env = "SELECTORS_RELOAD_INTERVAL_SECS")]
+    pub selectors_reload_interval_secs: Option<u64>,
+
+    /// 사이트 주소 파일 경로. 기본값 sites.toml, 없으면 빌드에 포함된 값 사용
+    #[arg(long, env = "SITES_PATH")]
+    pub sites_path: Option<PathBuf>,
+
+    /// PowerPlanner 주소. 사이트 주소 파일보다 우선
+    #[arg(long, env = "PP_BASE_URL")]
+    pub pp_base_url: Option<String>,
+
+    /// 한전ON 주소. 사이트 주소 파일보다 우선
+    #[arg(long, env = "KEPCO_ON_BASE_URL")]
+    pub kepco_on_base_url: Option<String>,
+
+    /// canary 점검용 계정. id / 비밀번호 / 고객번호를 모두 지정해야 함
+    #[arg(long, env = "CANARY_USER_ID")]
+    pub canary_user_id: Option<String>,
+
+    #[arg(long, env = "CANARY_USER_PW", hide_env_values = true)]
+    pub canary_user_pw: Option<String>,
+
+    #[arg(long, env = "CANARY_USER_NUM")]
+    pub canary_user_num: Option<String>,
+
+    /// canary 자동 실행 주기. 기본값 21600, 0 이면 비활성화
+    #[arg(long, env = "CANARY_INTERVAL_SECS")]
+    pub canary_interval_secs: Option<u64>,
+}
+
+impl ConfigValues {
+    fn from_file(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read config file {}: {:?}", path.display(), e))?;
+
+        toml::from_str(&text).map_err(|e| anyhow!("Invalid config file {}: {}", path.display(), e))
+    }
+
+    // self 에 없는 항목만 lower 에서 채움
+    fn or(self, lower: ConfigValues) -> Self {
+        ConfigValues {
+            config: self.config.or(lower.config),
+            app_name_version: self.app_name_version.or(lower.app_name_version),
+            listen_address: self.listen_address.or(lower.listen_address),
+            driver_path: self.driver_path.or(lower.driver_path),
+            driver_port: self.driver_port.or(lower.driver_port),
+            browser_binary: self.browser_binary.or(lower.browser_binary),
+            browser_binary_test: self.browser_binary_test.or(lower.browser_binary_test),
+            browser_no_sandbox: self.browser_no_sandbox.or(lower.browser_no_sandbox),
+            page_load_timeout_secs: self.page_load_timeout_secs.or(lower.page_load_timeout_secs),
+            pool_min_size: self.pool_min_size.or(lower.pool_min_size),
+            pool_max_size: self.pool_max_size.or(lower.pool_max_size),
+            pool_checkout_timeout_secs: self
+                .pool_checkout_timeout_secs
+                .or(lower.pool_checkout_timeout_secs),
+            pool_health_check_interval_secs: self
+                .pool_health_check_interval_secs
+                .or(lower.pool_health_check_interval_secs),
+            artifacts_dir: self.artifacts_dir.or(lower.artifacts_dir),
+            api_keys_path: self.api_keys_path.or(lower.api_keys_path),
+            api_keys: self.api_keys.or(lower.api_keys),
+            client_max_concurrent: self.client_max_concurrent.or(lower.client_max_concurrent),
+            client_daily_quota: self.client_daily_quota.or(lower.client_daily_quota),
+            selectors_path: self.selectors_path.or(lower.selectors_path),
+            selectors_reload_interval_secs: self
+                .selectors_reload_interval_secs
+                .or(lower.selectors_reload_interval_secs),
+            sites_path: self.sites_path.or(lower.sites_path),
+            pp_base_url: self.pp_base_url.or(lower.pp_base_url),
+            kepco_on_base_url: self.kepco_on_base_url.or(lower.kepco_on_base_url),
+            canary_user_id: self.canary_user_id.or(lower.canary_user_id),
+            canary_user_pw: self.canary_user_pw.or(lower.canary_user_pw),
+            canary_user_num: self.canary_user_num.or(lower.canary_user_num),
+            canary_interval_secs: self.canary_interval_secs.or(lower.canary_interval_secs),
+        }
+    }
+}
+
+// 검증이 끝난 서버 설정
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub app_name_version: String,
+    pub listen_address: SocketAddr,
+    pub driver_path: String,
+    pub driver_port: u16,
+    pub browser: BrowserConfig,
+    pub pool: PoolConfig,
+    pub artifacts: ArtifactConfig,
+    pub api_keys: ApiKeyConfig,
+    pub selectors: SelectorConfig,
+    pub sites: SiteConfig,
+    pub canary: CanaryConfig,
+}
+
+impl ServerConfig {
+    // CLI flag 와 환경변수를 읽고, 설정 파일이 있으면 그 아래에 깔음.
+    // 잘못된 flag 나 --help 는 clap 이 출력 후 종료
+    pub fn load() -> Result<Self> {
+        let values = ConfigValues::parse();
+
+        let file = match &values.config {
+            Some(path) => ConfigValues::from_file(path)?,
+            None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                ConfigValues::from_file(Path::new(DEFAULT_CONFIG_PATH))?
+            }
+            None => ConfigValues::default(),
+        };
+
+        Self::resolve(values.or(file))
+    }
+
+    pub fn resolve(values: ConfigValues) -> Result<Self> {
+        let app_name_version = values.app_name_version.unwrap_or_else(|| {
+            format!("{} v{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+        });
+
+        let listen_address = values
+            .listen_address
+            .as_deref()
+            .unwrap_or(DEFAULT_LISTEN_ADDRESS);
+        let listen_address = listen_address
+            .parse::<SocketAddr>()
+            .map_err(|e| anyhow!("Invalid listen_address ({}): {}", listen_address, e))?;
+
+        let driver_path = values.driver_path.ok_or_else(|| {
+            anyhow!("driver_path is required (--driver-path, CHROME_DRIVER_PATH or config file)")
+        })?;
+        let driver_port = values.driver_port.unwrap_or(DEFAULT_DRIVER_PORT);
+
+        let binary = values.browser_binary.ok_or_else(|| {
+            anyhow!(
+                "browser_binary is required (--browser-binary, CHROME_BINARY_PATH or config file)"
+            )
+        })?;
+        let page_load_timeout_secs = values
+            .page_load_timeout_secs
+            .unwrap_or(DEFAULT_PAGE_LOAD_TIMEOUT_SECS);
+        if page_load_timeout_secs == 0 {
+            return Err(anyhow!("page_load_timeout_secs must be greater than 0"));
+        }
+        let browser = BrowserConfig {
+            test_binary: values.browser_binary_test.unwrap_or_else(|| binary.clone()),
+            binary,
+            no_sandbox: values.browser_no_sandbox.unwrap_or(false),
+            page_load_timeout: Duration::from_secs(page_load_timeout_secs),
+        };
+
+        let default = PoolConfig::default();
+        let pool = PoolConfig {
+            min_size: values.pool_min_size.unwrap_or(default.min_size),
+            max_size: values.pool_max_size.unwrap_or(default.max_size),
+            checkout_timeout: values
+                .pool_checkout_timeout_secs
+                .map(Duration::from_secs)
+                .unwrap_or(default.checkout_timeout),
+            health_check_interval: values
+                .pool_health_check_interval_secs
+                .map(Duration::from_secs)
+                .unwrap_or(default.health_check_interval),
+        };
+        pool.validate()?;
+
+        let artifacts = ArtifactConfig {
+            dir: values
+                .artifacts_dir
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_ARTIFACTS_DIR)),
+        };
+
+        let listed = match &values.api_keys {
+            Some(list) => ApiKeyFile::from_list(list)?,
+            None => ApiKeyFile::default(),
+        };
+        let file = match &values.api_keys_path {
+            Some(path) => ApiKeyFile::from_file(path)?,
+            None => ApiKeyFile::default(),
+        };
+        let default = ClientLimits::default();
+        let api_keys = ApiKeyConfig {
+            clients: listed.merge(file),
+            defaults: ClientLimits {
+                max_concurrent: values
+                    .client_max_concurrent
+                    .unwrap_or(default.max_concurrent),
+                daily_quota: values.client_daily_quota.unwrap_or(default.daily_quota),
+            },
+        };
+        api_keys.clients.validate()?;
+
+        let default = SelectorConfig::default();
+        let selectors = SelectorConfig {
+            path: values.selectors_path.unwrap_or(default.path),
+            reload_interval: values
+                .selectors_reload_interval_secs
+                .map(Duration::from_secs)
+                .unwrap_or(default.reload_interval),
+        };
+        if selectors.reload_interval.is_zero() {
+            return Err(anyhow!(
+                "selectors_reload_interval_secs must be greater than 0"
+            ));
+        }
+
+        let mut base_urls = BTreeMap::new();
+        for (site, base_url) in [
+            ("pp", values.pp_base_url),
+            ("kepco_on", values.kepco_on_base_url),
+        ] {
+            if let Some(base_url) = base_url {
+                base_urls.insert(site.to_string(), base_url);
+            }
+        }
+        let sites = SiteConfig {
+            path: values
+                .sites_path
+                .unwrap_or_else(|| SiteConfig::default().path),
+            base_urls,
+        };
+
+        let account = match (
+            values.canary_user_id,
+            values.canary_user_pw,
+            values.canary_user_num,
+        ) {
+            (Some(user_id), Some(user_pw), Some(user_num)) => Some(PpRequestBody {
+                userId: user_id,
+                userPw: user_pw,
+                userNum: user_num,
+                testMode: None,
+            }),
+            (None, None, None) => None,
+            _ => {
+                return Err(anyhow!(
+                    "canary_user_id, canary_user_pw and canary_user_num must be set together"
+                ));
+            }
+        };
+        let canary = CanaryConfig::new(
+            account,
+            values
+                .canary_interval_secs
+                .unwrap_or(DEFAULT_CANARY_INTERVAL_SECS),
+        );
+
+        Ok(ServerConfig {
+            app_name_version,
+            listen_address,
+            driver_path,
+            driver_port,
+            browser,
+            pool,
+            artifacts,
+            api_keys,
+            selectors,
+            sites,
+            canary,
+        })
+    }
+
+    // 로컬 chromedriver 주소
+    pub fn driver_url(&self) -> String {
+        format!("http://localhost:{}", self.driver_port)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn required() -> ConfigValues {
+        ConfigValues {
+            driver_path: Some("/usr/bin/chromedriver".to_string()),
+            browser_binary: Some("/usr/bin/chromium".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn cli_definition_is_valid() {
+        ConfigValues::command().debug_assert();
+    }
+
+    #[test]
+    fn higher_layer_wins_and_defaults_fill_the_rest() {
+        let file: ConfigValues = toml::from_str(
+            r#"
+            listen_address = "127.0.0.1:8080"
+            driver_port = 9515
+            pool_max_size = 8
+            "#,
+        )
+        .unwrap();
+        let cli = ConfigValues {
+            driver_port: Some(4444),
+            ..required()
+        };
+
+        let config = ServerConfig::resolve(cli.or(file)).unwrap();
+
+        assert_eq!(config.listen_address, "127.0.0.1:8080".parse().unwrap());
+        assert_eq!(config.driver_url(), "http://localhost:4444");
+        assert_eq!(config.pool.max_size, 8);
+        assert_eq!(config.pool.min_size, PoolConfig::default().min_size);
+        assert_eq!(config.browser.test_binary, "/usr/bin/chromium");
+        assert!(config.api_keys.clients.clients.is_empty());
+    }
+
+    #[test]
+    fn selector_site_and_canary_settings_are_layered() {
+        let file: ConfigValues = toml::from_str(
+            r#"
+            selectors_path = "/etc/crawler/selectors.toml"
+            sites_path = "/etc/crawler/sites.toml"
+            pp_base_url = "http://mirror.local"
+            kepco_on_base_url = "http://kepco-on.local"
+            canary_user_id = "canary"
+            canary_user_pw = "secret"
+            canary_user_num = "0123456789"
+            "#,
+        )
+        .unwrap();
+        let cli = ConfigValues {
+            pp_base_url: Some("http://localhost:8080".to_string()),
+            canary_interval_secs: Some(0),
+            ..required()
+        };
+
+        let config = ServerConfig::resolve(cli.or(file)).unwrap();
+
+        assert_eq!(
+            config.selectors.path,
+            PathBuf::from("/etc/crawler/selectors.toml")
+        );
+        assert_eq!(
+            config.selectors.reload_interval,
+            SelectorConfig::default().reload_interval
+        );
+        assert_eq!(config.sites.path, PathBuf::from("/etc/crawler/sites.toml"));
+        assert_eq!(config.sites.base_urls["pp"], "http://localhost:8080");
+        assert_eq!(config.sites.base_urls["kepco_on"], "http://kepco-on.local");
+        assert_eq!(config.canary.account.unwrap().userNum, "0123456789");
+        assert!(config.canary.interval.is_none());
+
+        let defaults = ServerConfig::resolve(required()).unwrap();
+        assert_eq!(defaults.sites.path, SiteConfig::default().path);
+        assert!(defaults.sites.base_urls.is_empty());
+        assert!(defaults.canary.account.is_none());
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        assert!(ServerConfig::resolve(ConfigValues::default()).is_err());
+        assert!(
+            ServerConfig::resolve(ConfigValues {
+                listen_address: Some("not-an-address".to_string()),
+                ..required()
+            })
+            .is_err()
+        );
+        assert!(
+            ServerConfig::resolve(ConfigValues {
+                pool_min_size: Some(5),
+                pool_max_size: Some(2),
+                ..required()
+            })
+            .is_err()
+        );
+        assert!(toml::from_str::<ConfigValues>("unknown_key = 1").is_err());
+        assert!(
+            ServerConfig::resolve(ConfigValues {
+                api_keys: Some("erp".to_string()),
+                ..required()
+            })
+            .is_err()
+        );
+        assert!(
+            ServerConfig::resolve(ConfigValues {
+                canary_user_id: Some("canary".to_string()),
+                ..required()
+            })
+            .is_err()
+        );
+        assert!(
+            ServerConfig::resolve(ConfigValues {
+                selectors_reload_interval_secs: Some(0),
+                ..required()
+            })
+            .is_err()
+        );
+        assert!(
+            ServerConfig::resolve(ConfigValues {
+                pool_health_check_interval_secs: Some(0),
+                ..required()
+            })
+            .is_err()
+        );
+    }
+}
@@ -1,54 +1,180 @@
 use anyhow::{Result, anyhow};
-use std::str::FromStr;
-use std::{env::var, net::SocketAddr};
+use std::net::SocketAddr;
+use std::sync::Arc;
 
 use crate::handlers::{
+    driver::{
+        canary::{get_canary_latest_handler, post_canary_handler},
+        status::get_driver_status_handler,
+    },
+    jobs::crawl_jobs::{
+        cancel_crawl_job_handler, crawl_job_progress_ws_handler, get_crawl_job_handler,
+        submit_crawl_job_handler,
+    },
     legacy_kepco::{
         kepco::get_3year_kepco_data_of_handler,
         pp_kepco::{get_latest_3_pp_paid_data_handler, get_pp_all_periods_paid_data_handler},
     },
-    pp::user_info::get_user_info_handler,
+    pp::{
+        batch::get_pp_batch_handler, bill::get_pp_bill_breakdown_handler,
+        bill_pdf::get_pp_bill_pdf_handler, customers::get_pp_customers_handler,
+        demand::get_pp_demand_history_handler, load_profile::get_pp_load_profile_handler,
+        usage::get_pp_usage_aggregates_handler, user_info::get_user_info_handler,
+    },
+    tariff::{compare::post_tariff_compare_handler, estimate::post_tariff_estimate_handler},
+};
+use crate::server_init::{app_state::AppState, config::ServerConfig};
+use crate::utils::{
+    artifacts::ArtifactStore,
+    auth::{ApiKeyStore, limit_crawl, require_admin, require_api_key, require_ws_api_key},
+    canary::CanaryRunner,
+    drivers::SessionPool,
+    jobs::JobStore,
+    selectors::{SelectorRegistry, init_selectors},
+    sites::{init_sites, load_sites},
+    supervisor::DriverSupervisor,
 };
 use axum::extract::DefaultBodyLimit;
-use axum::routing::post;
+use axum::middleware;
+use axum::routing::{get, post};
 use chrono::{DateTime, Utc};
 
 #[inline]
 pub async fn server_initializer(
     start: tokio::time::Instant,
     server_start_time: DateTime<Utc>,
+    driver: Arc<DriverSupervisor>,
+    config: ServerConfig,
 ) -> Result<String> {
-    // 각종 환경변수들을 여기서 가져올 것.
-    // Save env. variables here.
-    let app_name_version: String = match var("APP_NAME_VERSION") {
-        Ok(var) => var,
+    // 서버 설정은 main 에서 검증을 마치고 넘겨받음.
+    // The server config is loaded and validated in main.
+    let app_name_version: String = config.app_name_version.clone();
+    let hosting_address: SocketAddr = config.listen_address;
+
+    // x-api-key 로 클라이언트 식별. 키가 하나도 없으면 시작하지 않음.
+    // API clients identified by x-api-key. Refuses to start without any key.
+    let clients = match ApiKeyStore::new(config.api_keys.clone()) {
+        Ok(clients) => clients,
         Err(e) => {
-            return Err(anyhow!("Could not find var APP_NAME_VERSION: {:?}", e));
+            return Err(anyhow!("Could not load api keys: {:?}", e));
         }
     };
 
-    // 호스팅할 IP를 여기서 %str로 파싱하거나 환경변수에서 로딩할 것.
-    // Load host IP address here.
-    let hosting_address: SocketAddr = match SocketAddr::from_str("[::]:30737") {
-        Ok(addr) => addr,
+    // WebDriver 세션 풀. 최소 세션 수는 maintenance task 가 채워둠.
+    // WebDriver session pool. The maintenance task keeps min_size sessions warm.
+    let pool = SessionPool::new(
+        &config.driver_url(),
+        config.pool.clone(),
+        config.browser.clone(),
+        driver.subscribe(),
+    );
+    let _pool_maintenance = pool.spawn_maintenance();
+
+    // 비동기 크롤링 job 저장소. 완료된 job 은 일정 시간 후 정리됨.
+    // Async crawl job store. Finished jobs are evicted after a while.
+    let jobs = JobStore::new();
+    let _jobs_cleanup = jobs.spawn_cleanup();
+
+    // 사이트 selector registry. 파일이 바뀌면 재시작 없이 다시 읽음.
+    // Site selector registry, reloaded without restart when the file changes.
+    let selectors = match SelectorRegistry::from_config(&config.selectors) {
+        Ok(registry) => registry,
         Err(e) => {
-            return Err(anyhow!("Could not parse socket address: {:?}", e));
+            return Err(anyhow!("Could not load selectors: {:?}", e));
         }
     };
+    let _selectors_reload = selectors.spawn_reload();
+    init_selectors(selectors);
 
-    // 인증 필요 없는 자료용. x-api-key로 대부분 접근은 걸러져서 민감하지 않은 정보 표출할 때 사용. 또는 테스트용.
-    // For insensitive information that only requires x-api-key filtering. Or for testing.
-    let insensitives_router: axum::Router = axum::Router::new()
+    // 크롤링 대상 사이트 주소. staging mirror / mock / proxy 를 가리킬 수 있음.
+    // Target site base URLs and page paths, overridable for mirrors, mocks or proxies.
+    let sites = match load_sites(&config.sites) {
+        Ok(sites) => sites,
+        Err(e) => {
+            return Err(anyhow!("Could not load sites: {:?}", e));
+        }
+    };
+    init_sites(sites);
+
+    // 크롤링 실패 시 screenshot / page source / console log 저장 위치
+    // Where failure artifacts (screenshot / page source / console logs) are stored.
+    let artifacts = ArtifactStore::new(config.artifacts.clone());
+
+    // selector drift 점검. 테스트 계정이 설정되어 있으면 주기적으로 실행.
+    // Selector drift canary, scheduled when a test account is configured.
+    let canary = CanaryRunner::new(config.canary.clone());
+    let _canary_schedule = canary.spawn_schedule(pool.clone());
+
+    let state: AppState = AppState {
+        pool,
+        driver,
+        jobs,
+        artifacts,
+        canary,
+        clients,
+    };
+
+    // 브라우저 세션을 사용하는 크롤링 라우트. 클라이언트별 동시 실행 수 / 일일 횟수 제한.
+    // Crawl routes using a browser session, limited per client in concurrency and daily count.
+    let crawl_router: axum::Router<AppState> = axum::Router::new()
         .route("/crawling/legacy_kepco/3year", post(get_3year_kepco_data_of_handler))
         .route("/crawling/pp/paid/all-periods", post(get_pp_all_periods_paid_data_handler))
         .route("/crawling/pp/paid/latest-3-data", post(get_latest_3_pp_paid_data_handler))
-        .route("/crawling/pp/user-info", post(get_user_info_handler));
+        .route("/crawling/pp/paid/breakdown", post(get_pp_bill_breakdown_handler))
+        .route("/crawling/pp/paid/bill-pdf", post(get_pp_bill_pdf_handler))
+        .route("/crawling/pp/user-info", post(get_user_info_handler))
+        .route("/crawling/pp/batch", post(get_pp_batch_handler))
+        .route("/crawling/pp/customers", post(get_pp_customers_handler))
+        .route("/crawling/pp/usage/load-profile", post(get_pp_load_profile_handler))
+        .route("/crawling/pp/usage/aggregates", post(get_pp_usage_aggregates_handler))
+        .route("/crawling/pp/usage/demand", post(get_pp_demand_history_handler))
+        .route_layer(middleware::from_fn_with_state(state.clone(), limit_crawl));
+
+    // 비동기 크롤링 job 등록. 핸들러가 job 이 끝날 때까지 동시 실행 슬롯을 잡고 있음.
+    // Crawl job submission. The handler holds the concurrency slot until the job ends.
+    let jobs_router: axum::Router<AppState> =
+        axum::Router::new().route("/crawling/jobs", post(submit_crawl_job_handler));
 
-    // 최종 라우터.
-    // The final router.
+    // 크롤링 없이 조회 / 계산만 하는 라우트. x-api-key 만 확인.
+    // Routes that only read or compute, requiring a valid x-api-key only.
+    let insensitives_router: axum::Router<AppState> = axum::Router::new()
+        .route("/crawling/jobs/{id}", get(get_crawl_job_handler).delete(cancel_crawl_job_handler))
+        .route("/tariff/estimate", post(post_tariff_estimate_handler))
+        .route("/tariff/compare", post(post_tariff_compare_handler));
+
+    // WebSocket 라우트. 브라우저는 헤더를 붙일 수 없으므로 Sec-WebSocket-Protocol 의 키도 허용.
+    // WebSocket routes, also accepting the key from Sec-WebSocket-Protocol for browsers.
+    let websocket_router: axum::Router<AppState> = axum::Router::new()
+        .route("/crawling/jobs/{id}/progress", get(crawl_job_progress_ws_handler))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&state.clients),
+            require_ws_api_key,
+        ));
+
+    // 운영용 라우트. admin 클라이언트만 사용 가능.
+    // Operational routes, for admin clients only.
+    let admin_router: axum::Router<AppState> = axum::Router::new()
+        .route("/admin/canary", post(post_canary_handler))
+        .route("/admin/canary/latest", get(get_canary_latest_handler))
+        .route_layer(middleware::from_fn(require_admin));
+
+    // health check 용. 인증 없이 접근 가능.
+    // For health checks, open without x-api-key.
+    let public_router: axum::Router<AppState> =
+        axum::Router::new().route("/driver/status", get(get_driver_status_handler));
+
+    // 최종 라우터. public 을 제외한 모든 라우트는 x-api-key 로 걸러짐.
+    // The final router. Every route except the public ones is filtered by x-api-key.
     let app: axum::Router = axum::Router::new()
+        .merge(crawl_router)
+        .merge(jobs_router)
         .merge(insensitives_router)
-        .layer(DefaultBodyLimit::disable()); // 64MB
+        .merge(admin_router)
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_api_key))
+        .merge(websocket_router)
+        .merge(public_router)
+        .layer(DefaultBodyLimit::disable()) // 64MB
+        .with_state(state);
 
     // Tokio TCP listener에 IP를 연결해주고 오류처리.
     // Bind IP address to the Tokio TCP listener here.
@@ -63,21 +189,20 @@ pub async fn server_initializer(
     // 나중에 오류처리로 넘길 것.
     // Handle error later.
     println!(
-        "{}",
-        format!(
-            "{} started successfully on {} in {:?}.",
-            app_name_version,
-            hosting_address,
-            start.elapsed()
-        )
+        "{} started successfully on {} at {} in {:?}.",
+        app_name_version,
+        hosting_address,
+        server_start_time,
+        start.elapsed()
     );
 
-    // 여기서 앱을 Axum으로 서빙.
-    // Serve app with Axum here.
+    // 여기서 앱을 Axum으로 서빙. SIGTERM / Ctrl+C 수신 시 진행 중인 요청을 마치고 종료.
+    // Serve app with Axum here. Shuts down gracefully on SIGTERM / Ctrl+C.
     match axum::serve(
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
+    .with_graceful_shutdown(shutdown_signal())
     .await
     {
         Ok(_) => (),
@@ -88,3 +213,34 @@ pub async fn server_initializer(
 
     Ok(String::from("Server exiting."))
 }
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            eprintln!("Failed to install Ctrl+C handler: {:?}", e);
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                eprintln!("Failed to install SIGTERM handler: {:?}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    println!("Shutdown signal received, draining requests.");
+}
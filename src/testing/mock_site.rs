@@ -0,0 +1,111 @@
+use axum::{
+    Form, Router,
+    http::header,
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::{get, post},
+};
+use serde_derive::Deserialize;
+use std::sync::{Arc, LazyLock};
+
+use crate::models::driver::sites::{DEFAULT_SITES, SiteFile, Sites};
+use crate::models::handler::pp::commons::{PP_SITE, PP_USER_INFO};
+use crate::utils::sites::{init_sites, page_url};
+
+pub const FIXTURE_USER_ID: &str = "fixture";
+pub const FIXTURE_USER_PW: &str = "fixture-pw";
+pub const FIXTURE_USER_NUM: &str = "0123456789";
+pub const KEPCO_ON_CLAIM_PATH: &str = "/kepco_on/claim";
+
+const INTRO_HTML: &str = include_str!("../../tests/fixtures/pp/intro.html");
+const HOME_HTML: &str = include_str!("../../tests/fixtures/pp/home.html");
+const USER_INFO_HTML: &str = include_str!("../../tests/fixtures/pp/user_info.html");
+const SELECT_CHARGE_HTML: &str = include_str!("../../tests/fixtures/pp/select_charge.html");
+const MONTHLY_CLAIM_HTML: &str = include_str!("../../tests/fixtures/pp/monthly_claim.html");
+const LOADING_JS: &str = include_str!("../../tests/fixtures/pp/loading.js");
+const KEPCO_ON_CLAIM_HTML: &str = include_str!("../../tests/fixtures/kepco_on/claim.html");
+const LOGIN_MESSAGE: &str = "<!--LOGIN_MESSAGE-->";
+const LOGIN_REJECTED_SCRIPT: &str =
+    "<script>alert('아이디 또는 비밀번호가 일치하지 않습니다.');</script>";
+
+// 테스트 프로세스 전체에서 하나만 띄움. 테스트마다 runtime 이 다르므로 별도 thread 에서 실행
+static MOCK_SITE: LazyLock<String> = LazyLock::new(|| {
+    let listener =
+        std::net::TcpListener::bind("127.0.0.1:0").expect("Failed to bind mock site listener");
+    listener
+        .set_nonblocking(true)
+        .expect("Failed to set mock site listener non-blocking");
+    let url = format!(
+        "http://{}",
+        listener
+            .local_addr()
+            .expect("Failed to read mock site address")
+    );
+
+    let file = SiteFile::parse(DEFAULT_SITES)
+        .expect("embedded sites.toml is invalid")
+        .with_base_url(PP_SITE, &url);
+    init_sites(Arc::new(
+        Sites::resolve(file).expect("Failed to point sites at the mock site"),
+    ));
+
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to build mock site runtime");
+        runtime.block_on(async move {
+            let listener = tokio::net::TcpListener::from_std(listener)
+                .expect("Failed to register mock site listener");
+            axum::serve(listener, router())
+                .await
+                .expect("Mock site stopped");
+        });
+    });
+
+    url
+});
+
+// mock PowerPlanner 주소. 크롤러의 pp 사이트 주소도 이 주소로 지정됨
+pub fn mock_site_url() -> &'static str {
+    let url = MOCK_SITE.as_str();
+    assert!(
+        page_url(PP_USER_INFO).is_ok_and(|page| page.starts_with(url)),
+        "Sites were initialized before the mock site started"
+    );
+    url
+}
+
+fn router() -> Router {
+    Router::new()
+        .route("/", get(Html(INTRO_HTML)))
+        .route("/login", post(login))
+        .route("/rm/rm0101.do", get(Html(HOME_HTML)))
+        .route("/mb/mb0101.do", get(Html(USER_INFO_HTML)))
+        .route("/pf/pf0101_1.do", get(Html(SELECT_CHARGE_HTML)))
+        .route("/cm/cm0101.do", get(Html(MONTHLY_CLAIM_HTML)))
+        .route("/js/loading.js", get(loading_js))
+        // 한전ON 요금조회 화면. 사이트 주소는 바꾸지 않고 테스트에서 직접 이동
+        .route(KEPCO_ON_CLAIM_PATH, get(Html(KEPCO_ON_CLAIM_HTML)))
+}
+
+#[derive(Deserialize)]
+struct LoginForm {
+    user_id: String,
+    user_pw: String,
+}
+
+// 로그인 성공 시 홈으로, 실패 시 intro 화면에 alert
+async fn login(Form(form): Form<LoginForm>) -> Response {
+    if form.user_id == FIXTURE_USER_ID && form.user_pw == FIXTURE_USER_PW {
+        return Redirect::to("/rm/rm0101.do?menu_id=O010101").into_response();
+    }
+
+    Html(INTRO_HTML.replace(LOGIN_MESSAGE, LOGIN_REJECTED_SCRIPT)).into_response()
+}
+
+async fn loading_js() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/javascript")],
+        LOADING_JS,
+    )
+}
@@ -0,0 +1,268 @@
+use chrono::NaiveDate;
+use fantoccini::{Client, Locator};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::handlers::legacy_kepco::kepco::parsing_options_data;
+use crate::handlers::legacy_kepco::pp_kepco::{pp_all_periods_paid_data, pp_latest_paid_data};
+use crate::handlers::pp::commons::{pp_customers, pp_login, pp_sign_in};
+use crate::handlers::pp::user_info::pp_user_info;
+use crate::models::driver::chromes::{BrowserConfig, ChromeOptions};
+use crate::models::error::crawl_errors::CrawlError;
+use crate::models::handler::jobs::progress::ProgressEvent;
+use crate::models::handler::pp::commons::PpRequestBody;
+use crate::testing::mock_site::{
+    FIXTURE_USER_ID, FIXTURE_USER_NUM, FIXTURE_USER_PW, KEPCO_ON_CLAIM_PATH, mock_site_url,
+};
+use crate::utils::{drivers::create_client, progress::ProgressReporter};
+
+// 브라우저 테스트는 #[ignore] 이므로 cargo test -- --ignored 로 실행. 이때 둘 다 필요
+const FIXTURE_WEBDRIVER_URL: &str = "FIXTURE_WEBDRIVER_URL";
+const FIXTURE_BROWSER_BINARY: &str = "CHROME_BINARY_PATH_TEST";
+
+fn fixture_account() -> PpRequestBody {
+    PpRequestBody {
+        userId: FIXTURE_USER_ID.to_string(),
+        userPw: FIXTURE_USER_PW.to_string(),
+        userNum: FIXTURE_USER_NUM.to_string(),
+        testMode: None,
+    }
+}
+
+// ChromeOptions 의 user-data-dir 은 세션이 끝날 때까지 유지해야 함
+async fn fixture_client() -> (Client, ChromeOptions) {
+    let (Ok(webdriver_url), Ok(binary)) = (
+        std::env::var(FIXTURE_WEBDRIVER_URL),
+        std::env::var(FIXTURE_BROWSER_BINARY),
+    ) else {
+        panic!(
+            "Browser fixture tests need {} and {}",
+            FIXTURE_WEBDRIVER_URL, FIXTURE_BROWSER_BINARY
+        );
+    };
+    mock_site_url();
+
+    let browser = BrowserConfig {
+        test_binary: binary.clone(),
+        binary,
+        no_sandbox: true,
+        page_load_timeout: Duration::from_secs(30),
+    };
+    create_client(&webdriver_url, &browser, false)
+        .await
+        .expect("Failed to create WebDriver session")
+}
+
+async fn http_get(path: &str) -> (u16, String) {
+    let address = mock_site_url().trim_start_matches("http://");
+    let mut stream = TcpStream::connect(address).await.unwrap();
+    stream
+        .write_all(
+            format!(
+                "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+                path, address
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+
+    let mut buf = Vec::new();
+    stream.read_to_end(&mut buf).await.unwrap();
+    let response = String::from_utf8_lossy(&buf).to_string();
+    let status = response
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .unwrap_or_default();
+
+    (status, response)
+}
+
+#[tokio::test]
+async fn mock_site_serves_pp_pages() {
+    let pages = [
+        ("/", "RSA_USER_ID"),
+        ("/rm/rm0101.do?menu_id=O010101", "href=\"#0123456789\""),
+        ("/mb/mb0101.do?menu_id=O010601", "id=\"table3\""),
+        ("/pf/pf0101_1.do?menu_id=O010501", "spanCNTR_KND_NM"),
+        ("/cm/cm0101.do?menu_id=O010301", "id=\"grid\""),
+        ("/js/loading.js", "hideLoading"),
+        (KEPCO_ON_CLAIM_PATH, "mf_wfm_layout_slb_searchYm_input_0"),
+    ];
+
+    for (path, marker) in pages {
+        let (status, body) = http_get(path).await;
+        assert_eq!(status, 200, "{}", path);
+        assert!(
+            body.contains(marker),
+            "{} does not contain {}",
+            path,
+            marker
+        );
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires chromedriver"]
+async fn pp_login_selects_fixture_customer() {
+    let (client, _options) = fixture_client().await;
+
+    pp_login(&client, fixture_account(), &ProgressReporter::none())
+        .await
+        .unwrap();
+    let customers = pp_customers(&client).await.unwrap();
+    client.close().await.unwrap();
+
+    assert_eq!(customers.len(), 2);
+    assert_eq!(customers[0].user_num, FIXTURE_USER_NUM);
+    assert_eq!(customers[0].name.as_deref(), Some("테스트공장"));
+    assert_eq!(
+        customers[0].address.as_deref(),
+        Some("경기도 테스트시 공단로 1")
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires chromedriver"]
+async fn pp_sign_in_rejects_wrong_password() {
+    let (client, _options) = fixture_client().await;
+
+    let result = pp_sign_in(
+        &client,
+        FIXTURE_USER_ID,
+        "wrong-password",
+        &ProgressReporter::none(),
+    )
+    .await;
+    client.close().await.unwrap();
+
+    let error = result.unwrap_err();
+    assert!(
+        matches!(
+            error.downcast_ref::<CrawlError>(),
+            Some(CrawlError::LoginRejected(_))
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires chromedriver"]
+async fn pp_user_info_reads_info_tables() {
+    let (client, _options) = fixture_client().await;
+
+    pp_login(&client, fixture_account(), &ProgressReporter::none())
+        .await
+        .unwrap();
+    let user_info = pp_user_info(&client).await.unwrap();
+    client.close().await.unwrap();
+
+    assert_eq!(user_info.user_number, FIXTURE_USER_NUM);
+    // 산업용(을), 고압A 선택2
+    assert_eq!(user_info.contract_type_id, 8);
+    assert_eq!(user_info.purpose_id, 12);
+    assert_eq!(user_info.contract_power, 500.0);
+    assert_eq!(user_info.inspection_day, 15);
+    assert_eq!(user_info.instrument_number, "12345678");
+}
+
+#[tokio::test]
+#[ignore = "requires chromedriver"]
+async fn pp_paid_data_parses_claim_grid_for_every_year() {
+    let (client, _options) = fixture_client().await;
+
+    pp_login(&client, fixture_account(), &ProgressReporter::none())
+        .await
+        .unwrap();
+    let data = pp_all_periods_paid_data(&client, &ProgressReporter::none())
+        .await
+        .unwrap();
+    client.close().await.unwrap();
+
+    assert_eq!(data.len(), 5);
+    assert_eq!(
+        data[0].claim_date,
+        NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+    );
+    assert_eq!(data[0].usage, 98760.0);
+    assert_eq!(data[0].paid, 15432100);
+    assert_eq!(
+        data[4].claim_date,
+        NaiveDate::from_ymd_opt(2024, 11, 1).unwrap()
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires chromedriver"]
+async fn pp_latest_paid_data_moves_to_previous_year() {
+    let (client, _options) = fixture_client().await;
+
+    pp_login(&client, fixture_account(), &ProgressReporter::none())
+        .await
+        .unwrap();
+    let data = pp_latest_paid_data(&client, 4, &ProgressReporter::none())
+        .await
+        .unwrap();
+    client.close().await.unwrap();
+
+    let dates: Vec<NaiveDate> = data.iter().map(|entry| entry.claim_date).collect();
+    assert_eq!(
+        dates,
+        [(2025, 3), (2025, 2), (2025, 1), (2024, 12)]
+            .map(|(year, month)| NaiveDate::from_ymd_opt(year, month, 1).unwrap())
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires chromedriver"]
+async fn kepco_months_without_claim_are_skipped() {
+    let (client, _options) = fixture_client().await;
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let progress = {
+        let events = events.clone();
+        ProgressReporter::new(move |event| events.lock().unwrap().push(event))
+    };
+
+    client
+        .goto(&format!("{}{}", mock_site_url(), KEPCO_ON_CLAIM_PATH))
+        .await
+        .unwrap();
+    // 첫 옵션(2025년 03월)은 1년치 조회에 포함되므로 다음 옵션부터
+    let data = parsing_options_data(
+        &client,
+        Locator::Id("mf_wfm_layout_slb_searchYm_input_0"),
+        FIXTURE_USER_NUM,
+        &1,
+        &progress,
+    )
+    .await
+    .unwrap();
+    client.close().await.unwrap();
+
+    let dates: Vec<Option<NaiveDate>> = data.iter().map(|entry| entry.claim_date).collect();
+    assert_eq!(
+        dates,
+        [(2025, 2), (2024, 12)].map(|(year, month)| NaiveDate::from_ymd_opt(year, month, 1))
+    );
+    assert_eq!(data[1].unpaid, 480900);
+
+    // 2025년 01월은 건너뛰었어도 진행 상황은 보고됨
+    let periods: Vec<(String, usize)> = events
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|event| match event {
+            ProgressEvent::PeriodParsed { label, rows, .. } => Some((label.clone(), *rows)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        periods,
+        [("2025년 02월", 1), ("2025년 01월", 0), ("2024년 12월", 1)]
+            .map(|(label, rows)| (label.to_string(), rows))
+    );
+}
@@ -0,0 +1,106 @@
+use crate::models::{
+    driver::artifacts::{
+        ArtifactConfig, ArtifactMeta, CONSOLE_LOG_FILE, META_FILE, PAGE_SOURCE_FILE,
+        SCREENSHOT_FILE,
+    },
+    error::response_errors_def::ErrorResponseCode,
+};
+use crate::utils::drivers::browser_console_logs;
+use anyhow::{Result, anyhow};
+use axum::http::StatusCode;
+use chrono::Utc;
+use fantoccini::Client;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use uuid::Uuid;
+
+// 핸들러 실패 시 screenshot, page source, URL, console log 를 artifact id 디렉터리에 저장
+pub struct ArtifactStore {
+    dir: PathBuf,
+}
+
+impl ArtifactStore {
+    pub fn new(config: ArtifactConfig) -> Arc<Self> {
+        Arc::new(ArtifactStore { dir: config.dir })
+    }
+
+    // 실패한 결과면 artifact 를 남기고 에러 JSON 에 artifact_id 를 붙임
+    pub async fn capture_on_error<T>(
+        &self,
+        client: &Client,
+        result: Result<T, ErrorResponseCode>,
+    ) -> Result<T, ErrorResponseCode> {
+        match result {
+            Ok(value) => Ok(value),
+            Err(code) => Err(self.capture(client, code).await),
+        }
+    }
+
+    pub async fn capture(&self, client: &Client, code: ErrorResponseCode) -> ErrorResponseCode {
+        // 요청 검증 실패와 일일 횟수 초과는 브라우저 상태와 무관
+        if code.status_code == StatusCode::BAD_REQUEST
+            || code.status_code == StatusCode::TOO_MANY_REQUESTS
+        {
+            return code;
+        }
+
+        let artifact_id = Uuid::new_v4().to_string();
+        match self.write_artifacts(client, &artifact_id, &code).await {
+            Ok(dir) => {
+                eprintln!("[{}] Failure artifacts saved: {}", code.code, dir.display());
+                code.with_artifact_id(artifact_id)
+            }
+            Err(e) => {
+                eprintln!("[{}] Failed to save failure artifacts: {:?}", code.code, e);
+                code
+            }
+        }
+    }
+
+    async fn write_artifacts(
+        &self,
+        client: &Client,
+        artifact_id: &str,
+        code: &ErrorResponseCode,
+    ) -> Result<PathBuf> {
+        let dir = self.dir.join(artifact_id);
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| anyhow!("Failed to create artifact dir {}: {:?}", dir.display(), e))?;
+
+        // 각 항목은 best effort. 세션이 죽었으면 meta.json 만 남음
+        let mut files = Vec::new();
+
+        if let Ok(png) = client.screenshot().await {
+            write_file(&dir, SCREENSHOT_FILE, &png, &mut files);
+        }
+        if let Ok(html) = client.source().await {
+            write_file(&dir, PAGE_SOURCE_FILE, html.as_bytes(), &mut files);
+        }
+        if let Ok(logs) = browser_console_logs(client).await {
+            let logs = serde_json::to_vec_pretty(&logs).unwrap_or_default();
+            write_file(&dir, CONSOLE_LOG_FILE, &logs, &mut files);
+        }
+
+        let meta = ArtifactMeta {
+            artifact_id: artifact_id.to_string(),
+            code: code.code,
+            message: code.message.to_string(),
+            url: client.current_url().await.ok().map(|url| url.to_string()),
+            files,
+            captured_at: Utc::now(),
+        };
+        let meta = serde_json::to_vec_pretty(&meta)
+            .map_err(|e| anyhow!("Failed to serialize artifact meta: {:?}", e))?;
+        std::fs::write(dir.join(META_FILE), meta)
+            .map_err(|e| anyhow!("Failed to write artifact meta: {:?}", e))?;
+
+        Ok(dir)
+    }
+}
+
+fn write_file(dir: &Path, name: &str, contents: &[u8], files: &mut Vec<String>) {
+    match std::fs::write(dir.join(name), contents) {
+        Ok(_) => files.push(name.to_string()),
+        Err(e) => eprintln!("Failed to write artifact {}: {:?}", name, e),
+    }
+}
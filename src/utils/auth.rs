@@ -0,0 +1,408 @@
+use crate::models::auth::api_keys::{
+    API_KEY_HEADER, API_KEY_PROTOCOL, ApiKeyConfig, ClientIdentity, ClientLimits,
+};
+use crate::models::error::response_errors_def::ErrorResponseCode;
+use crate::server_init::app_state::AppState;
+use anyhow::{Result, anyhow};
+use axum::{
+    Extension,
+    extract::{Request, State},
+    http::{
+        HeaderMap, HeaderValue,
+        header::{RETRY_AFTER, SEC_WEBSOCKET_PROTOCOL},
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{Local, NaiveDate};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+struct ClientState {
+    identity: ClientIdentity,
+    limits: ClientLimits,
+    concurrency: Arc<Semaphore>,
+    // (날짜, 그날 사용한 크롤링 횟수). 날짜가 바뀌면 0 부터 다시 셈
+    usage: Mutex<(NaiveDate, u64)>,
+}
+
+// 크롤링 요청 동안 잡고 있는 동시 실행 슬롯
+pub struct CrawlPermit {
+    _permit: OwnedSemaphorePermit,
+    quota: CrawlQuota,
+}
+
+impl CrawlPermit {
+    pub fn quota(&self) -> CrawlQuota {
+        self.quota.clone()
+    }
+}
+
+// 클라이언트의 일일 크롤링 횟수. 배치는 요청 이후 고객번호 수만큼 추가로 차감
+#[derive(Clone)]
+pub struct CrawlQuota {
+    state: Arc<ClientState>,
+}
+
+impl CrawlQuota {
+    // 남은 횟수가 부족하면 차감하지 않고 DAILY_QUOTA_EXCEEDED
+    pub fn charge(&self, crawls: u64) -> Result<(), ErrorResponseCode> {
+        let today = Local::now().date_naive();
+        let mut usage = self.state.usage.lock().unwrap_or_else(|e| e.into_inner());
+        if usage.0 != today {
+            *usage = (today, 0);
+        }
+        if usage.1.saturating_add(crawls) > self.state.limits.daily_quota {
+            return Err(ErrorResponseCode::DAILY_QUOTA_EXCEEDED);
+        }
+        usage.1 += crawls;
+
+        Ok(())
+    }
+}
+
+// x-api-key 로 클라이언트를 찾고 클라이언트별 동시 실행 수 / 일일 크롤링 횟수를 제한
+pub struct ApiKeyStore {
+    clients: HashMap<String, Arc<ClientState>>,
+}
+
+impl ApiKeyStore {
+    pub fn new(config: ApiKeyConfig) -> Result<Arc<Self>> {
+        config.clients.validate()?;
+        if config.clients.clients.is_empty() {
+            return Err(anyhow!(
+                "No api keys configured (api_keys_path, API_KEYS_PATH or API_KEYS)"
+            ));
+        }
+
+        let today = Local::now().date_naive();
+        let mut clients = HashMap::new();
+        for (name, entry) in config.clients.clients {
+            let limits = ClientLimits {
+                max_concurrent: entry
+                    .max_concurrent
+                    .unwrap_or(config.defaults.max_concurrent),
+                daily_quota: entry.daily_quota.unwrap_or(config.defaults.daily_quota),
+            };
+            let state = Arc::new(ClientState {
+                identity: ClientIdentity {
+                    name,
+                    admin: entry.admin,
+                },
+                limits,
+                concurrency: Arc::new(Semaphore::new(limits.max_concurrent)),
+                usage: Mutex::new((today, 0)),
+            });
+            for key in entry.keys {
+                clients.insert(key, Arc::clone(&state));
+            }
+        }
+
+        Ok(Arc::new(ApiKeyStore { clients }))
+    }
+
+    pub fn authenticate(&self, key: &str) -> Option<ClientIdentity> {
+        self.clients.get(key).map(|state| state.identity.clone())
+    }
+
+    // 동시 실행 슬롯을 먼저 잡고, 잡은 경우에만 일일 횟수를 1 차감
+    pub fn acquire_crawl(&self, key: &str) -> Result<CrawlPermit, ErrorResponseCode> {
+        let state = self
+            .clients
+            .get(key)
+            .ok_or(ErrorResponseCode::INVALID_API_KEY)?;
+
+        let permit = Arc::clone(&state.concurrency)
+            .try_acquire_owned()
+            .map_err(|_| ErrorResponseCode::TOO_MANY_CONCURRENT_CRAWLS)?;
+
+        let quota = CrawlQuota {
+            state: Arc::clone(state),
+        };
+        quota.charge(1)?;
+
+        Ok(CrawlPermit {
+            _permit: permit,
+            quota,
+        })
+    }
+}
+
+pub fn api_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+}
+
+// 브라우저 WebSocket 은 헤더를 붙일 수 없으므로 subprotocol 로 키를 받음.
+// new WebSocket(url, ["x-api-key", key]) -> Sec-WebSocket-Protocol: x-api-key, <key>
+// query 로 받지 않는 이유는 URL 이 access log 에 남기 때문
+pub fn ws_protocol_api_key(headers: &HeaderMap) -> Option<&str> {
+    let mut protocols = headers
+        .get_all(SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim);
+
+    protocols.find(|protocol| *protocol == API_KEY_PROTOCOL)?;
+    protocols.next().filter(|key| !key.is_empty())
+}
+
+// 다음 날 0시(서버 로컬 시간)까지 남은 초
+fn seconds_until_reset() -> i64 {
+    let now = Local::now();
+    now.date_naive()
+        .succ_opt()
+        .and_then(|tomorrow| tomorrow.and_hms_opt(0, 0, 0))
+        .map(|midnight| (midnight - now.naive_local()).num_seconds().max(1))
+        .unwrap_or(1)
+}
+
+// 모든 라우트 공통. x-api-key 가 등록된 키가 아니면 401
+pub async fn require_api_key(
+    State(state): State<AppState>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, ErrorResponseCode> {
+    let identity = api_key(req.headers())
+        .and_then(|key| state.clients.authenticate(key))
+        .ok_or(ErrorResponseCode::INVALID_API_KEY)?;
+
+    req.extensions_mut().insert(identity);
+    Ok(next.run(req).await)
+}
+
+// WebSocket upgrade 라우트. x-api-key 헤더가 없으면 Sec-WebSocket-Protocol 의 키로 인증.
+// 핸들러는 upgrade 응답에 API_KEY_PROTOCOL 을 되돌려줘야 브라우저가 연결을 유지함
+pub async fn require_ws_api_key(
+    State(clients): State<Arc<ApiKeyStore>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, ErrorResponseCode> {
+    let identity = api_key(req.headers())
+        .or_else(|| ws_protocol_api_key(req.headers()))
+        .and_then(|key| clients.authenticate(key))
+        .ok_or(ErrorResponseCode::INVALID_API_KEY)?;
+
+    req.extensions_mut().insert(identity);
+    Ok(next.run(req).await)
+}
+
+// acquire_crawl 실패 응답. 일일 횟수 초과면 Retry-After 로 초기화까지 남은 초를 알려줌
+pub fn crawl_rejection(code: ErrorResponseCode) -> Response {
+    let quota_exceeded = code.code == ErrorResponseCode::DAILY_QUOTA_EXCEEDED.code;
+    let mut response = code.into_response();
+    if quota_exceeded && let Ok(value) = HeaderValue::from_str(&seconds_until_reset().to_string()) {
+        response.headers_mut().insert(RETRY_AFTER, value);
+    }
+    response
+}
+
+// 브라우저 세션을 사용하는 라우트. 응답이 끝날 때까지 동시 실행 슬롯을 잡고 있음.
+// 배치 핸들러가 고객번호 수만큼 추가 차감할 수 있도록 CrawlQuota 를 extension 으로 붙임.
+// job 등록은 job 이 끝날 때까지 슬롯을 잡아야 하므로 핸들러에서 직접 acquire_crawl 호출
+pub async fn limit_crawl(
+    State(state): State<AppState>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    let key =
+        api_key(req.headers()).ok_or_else(|| ErrorResponseCode::INVALID_API_KEY.into_response())?;
+
+    let permit = state.clients.acquire_crawl(key).map_err(crawl_rejection)?;
+    req.extensions_mut().insert(permit.quota());
+
+    Ok(next.run(req).await)
+}
+
+// /admin 라우트는 admin = true 인 클라이언트만 사용 가능
+pub async fn require_admin(
+    Extension(identity): Extension<ClientIdentity>,
+    req: Request,
+    next: Next,
+) -> Result<Response, ErrorResponseCode> {
+    if !identity.admin {
+        return Err(ErrorResponseCode::ADMIN_REQUIRED);
+    }
+
+    Ok(next.run(req).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::auth::api_keys::{ApiClientEntry, ApiKeyFile};
+
+    fn store(max_concurrent: usize, daily_quota: u64) -> Arc<ApiKeyStore> {
+        let mut clients = ApiKeyFile::from_list("erp:erp-key-1,erp:erp-key-2").unwrap();
+        clients.clients.insert(
+            "ops".to_string(),
+            ApiClientEntry {
+                keys: vec!["ops-key".to_string()],
+                admin: true,
+                ..Default::default()
+            },
+        );
+
+        ApiKeyStore::new(ApiKeyConfig {
+            clients,
+            defaults: ClientLimits {
+                max_concurrent,
+                daily_quota,
+            },
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn keys_resolve_to_client_identity() {
+        let store = store(1, 10);
+
+        assert_eq!(
+            store.authenticate("erp-key-1"),
+            store.authenticate("erp-key-2")
+        );
+        assert_eq!(store.authenticate("erp-key-1").unwrap().name, "erp");
+        assert!(!store.authenticate("erp-key-1").unwrap().admin);
+        assert!(store.authenticate("ops-key").unwrap().admin);
+        assert!(store.authenticate("unknown").is_none());
+    }
+
+    #[test]
+    fn concurrency_is_shared_by_all_keys_of_a_client() {
+        let store = store(1, 10);
+
+        let permit = store.acquire_crawl("erp-key-1").ok().expect("crawl permit");
+        let busy = store.acquire_crawl("erp-key-2").err().unwrap();
+        assert_eq!(
+            busy.code,
+            ErrorResponseCode::TOO_MANY_CONCURRENT_CRAWLS.code
+        );
+        assert!(store.acquire_crawl("ops-key").is_ok());
+
+        drop(permit);
+        assert!(store.acquire_crawl("erp-key-2").is_ok());
+    }
+
+    #[test]
+    fn daily_quota_counts_accepted_crawls_only() {
+        let store = store(1, 2);
+
+        let first = store.acquire_crawl("erp-key-1").ok().expect("crawl permit");
+        assert!(store.acquire_crawl("erp-key-1").is_err());
+        drop(first);
+        drop(store.acquire_crawl("erp-key-1").ok().expect("crawl permit"));
+
+        let exceeded = store.acquire_crawl("erp-key-1").err().unwrap();
+        assert_eq!(exceeded.code, ErrorResponseCode::DAILY_QUOTA_EXCEEDED.code);
+    }
+
+    // 실제 서버를 띄워 upgrade 요청의 상태 줄과 subprotocol 응답을 확인
+    async fn upgrade(headers: &str) -> String {
+        use axum::extract::ws::WebSocketUpgrade;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let app = axum::Router::new()
+            .route(
+                "/progress",
+                axum::routing::get(
+                    |Extension(identity): Extension<ClientIdentity>, ws: WebSocketUpgrade| async move {
+                        assert_eq!(identity.name, "erp");
+                        ws.protocols([API_KEY_PROTOCOL])
+                            .on_upgrade(|_socket| async {})
+                    },
+                ),
+            )
+            .route_layer(axum::middleware::from_fn_with_state(
+                store(1, 10),
+                require_ws_api_key,
+            ));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        let request = format!(
+            "GET /progress HTTP/1.1\r\nHost: {}\r\nConnection: Upgrade\r\nUpgrade: websocket\r\n\
+             Sec-WebSocket-Version: 13\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n{}\r\n",
+            address, headers
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+
+        let mut response = vec![0; 1024];
+        let read = stream.read(&mut response).await.unwrap();
+        String::from_utf8_lossy(&response[..read]).to_lowercase()
+    }
+
+    #[test]
+    fn ws_protocol_carries_the_api_key() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static("x-api-key, erp-key-1"),
+        );
+        assert_eq!(ws_protocol_api_key(&headers), Some("erp-key-1"));
+
+        headers.insert(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static("erp-key-1"),
+        );
+        assert_eq!(ws_protocol_api_key(&headers), None);
+        headers.insert(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static("x-api-key"),
+        );
+        assert_eq!(ws_protocol_api_key(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn websocket_upgrade_accepts_the_key_as_subprotocol() {
+        let accepted = upgrade("Sec-WebSocket-Protocol: x-api-key, erp-key-1\r\n").await;
+        assert!(accepted.starts_with("http/1.1 101"), "{}", accepted);
+        assert!(accepted.contains("sec-websocket-protocol: x-api-key\r\n"));
+
+        assert!(
+            upgrade("x-api-key: erp-key-2\r\n")
+                .await
+                .starts_with("http/1.1 101")
+        );
+
+        let rejected = upgrade("Sec-WebSocket-Protocol: x-api-key, unknown\r\n").await;
+        assert!(rejected.starts_with("http/1.1 401"), "{}", rejected);
+    }
+
+    #[test]
+    fn quota_charges_are_all_or_nothing() {
+        let store = store(2, 5);
+
+        let permit = store.acquire_crawl("erp-key-1").ok().expect("crawl permit");
+        let quota = permit.quota();
+        assert!(quota.charge(3).is_ok());
+
+        let exceeded = quota.charge(2).err().unwrap();
+        assert_eq!(exceeded.code, ErrorResponseCode::DAILY_QUOTA_EXCEEDED.code);
+        // 실패한 차감은 남은 횟수를 쓰지 않음
+        assert!(store.acquire_crawl("erp-key-2").is_ok());
+        assert!(store.acquire_crawl("erp-key-2").is_err());
+    }
+
+    #[test]
+    fn invalid_key_sets_are_rejected() {
+        assert!(ApiKeyFile::from_list("no-separator").is_err());
+        assert!(
+            ApiKeyFile::from_list("a:same,b:same")
+                .unwrap()
+                .validate()
+                .is_err()
+        );
+        assert!(
+            ApiKeyFile::parse("[clients.a]\nkeys = []")
+                .unwrap()
+                .validate()
+                .is_err()
+        );
+        assert!(ApiKeyStore::new(ApiKeyConfig::default()).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::handlers::driver::canary::canary_sites;
+use crate::models::driver::canary::{CanaryConfig, CanaryReport};
+use crate::models::error::response_errors_def::ErrorResponseCode;
+use crate::utils::drivers::SessionPool;
+use chrono::Utc;
+use std::sync::{Arc, RwLock};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+// selector drift 점검 실행기. 동시에 하나만 실행하고 마지막 결과를 보관
+pub struct CanaryRunner {
+    config: CanaryConfig,
+    running: Mutex<()>,
+    latest: RwLock<Option<CanaryReport>>,
+}
+
+impl CanaryRunner {
+    pub fn new(config: CanaryConfig) -> Arc<Self> {
+        Arc::new(CanaryRunner {
+            config,
+            running: Mutex::new(()),
+            latest: RwLock::new(None),
+        })
+    }
+
+    pub fn latest(&self) -> Option<CanaryReport> {
+        self.latest
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub async fn run(&self, pool: &Arc<SessionPool>) -> Result<CanaryReport, ErrorResponseCode> {
+        let account = self
+            .config
+            .account
+            .as_ref()
+            .ok_or(ErrorResponseCode::CANARY_NOT_CONFIGURED)?;
+        let Ok(_running) = self.running.try_lock() else {
+            return Err(ErrorResponseCode::CANARY_RUNNING);
+        };
+
+        let started_at = Utc::now();
+        let client = pool
+            .checkout(false)
+            .await
+            .map_err(|e| ErrorResponseCode::CHECKOUT_SESSION.caused_by(e))?;
+
+        let sites = canary_sites(&client, account).await;
+        let report = CanaryReport {
+            healthy: sites.iter().all(|site| site.healthy()),
+            started_at,
+            finished_at: Utc::now(),
+            sites,
+        };
+
+        if report.healthy {
+            println!("Canary finished: all selectors resolved");
+        } else {
+            eprintln!("Canary finished: selector drift detected");
+        }
+        *self.latest.write().unwrap_or_else(|e| e.into_inner()) = Some(report.clone());
+
+        Ok(report)
+    }
+
+    pub fn spawn_schedule(self: &Arc<Self>, pool: Arc<SessionPool>) -> Option<JoinHandle<()>> {
+        let interval = self.config.interval?;
+        let runner = Arc::clone(self);
+
+        Some(tokio::spawn(async move {
+            // 서버 기동 직후에는 실행하지 않음
+            let mut ticker =
+                tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = runner.run(&pool).await {
+                    eprintln!("Scheduled canary failed: {}", e.to_json());
+                }
+            }
+        }))
+    }
+}
@@ -1,61 +1,66 @@
-use crate::models::driver::chromes::ChromeOptions;
+use crate::models::driver::chromes::{BrowserConfig, ChromeOptions};
+use crate::models::driver::dialog::{DIALOG_POLL_INTERVAL, DialogAction};
+use crate::models::driver::pool::PoolConfig;
+use crate::models::driver::selectors::Selector;
+use crate::models::driver::supervisor::{DriverState, DriverStatus};
+use crate::models::error::crawl_errors::CrawlError;
+use crate::utils::sites::sites;
 use anyhow::{Result, anyhow};
+use axum::http::Method;
 use fantoccini::elements::Element;
-use fantoccini::wd::Capabilities;
+use fantoccini::error::{CmdError, ErrorStatus};
+use fantoccini::wd::{Capabilities, WebDriverCompatibleCommand};
 use fantoccini::{Client, ClientBuilder, Locator};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::process::{Child, Command};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore, watch};
+use tokio::task::JoinHandle;
 use tokio::time::timeout;
-
-pub async fn start_chromedriver() -> Result<Child> {
-    let path = std::env::var("CHROME_DRIVER_PATH")
-        .map_err(|e| anyhow!("Failed to get CHROME_DRIVER_PATH: {:?}", e))?;
-
-    let chromedriver_process = Command::new(path)
-        .arg("--port=4450")
-        .kill_on_drop(true)
-        .spawn()
-        .map_err(|e| {
-            eprintln!("Failed to start ChromeDriver: {:?}", e);
-            anyhow!("Failed to start ChromeDriver: {:?}", e)
-        })?;
-
-    println!("ChromeDriver started on port 4450");
-    Ok(chromedriver_process)
-}
-
-pub async fn shutdown_chromedriver(chromedriver_process: Arc<Mutex<Child>>) {
-    let mut process = chromedriver_process.lock().await;
-
-    if let Ok(Some(_status)) = process.try_wait() {
-        println!("ChromeDriver process already terminated");
-    } else {
-        if let Err(e) = process.kill().await {
-            eprintln!("Failed to kill ChromeDriver: {:?}", e);
-        } else {
-            println!("ChromeDriver process terminated");
-        }
-    }
-}
-
-pub fn create_capabilities(test: bool) -> Result<Capabilities> {
+use url::{ParseError, Url};
+
+const SESSION_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+// checkout 시 죽은 세션을 새 세션으로 교체하는 최대 횟수
+const SESSION_REPLACE_ATTEMPTS: usize = 2;
+// 세션 반환 시 이전 고객의 로그인 정보가 남지 않도록 비움
+const CLEAR_WEB_STORAGE_SCRIPT: &str =
+    "try { window.localStorage.clear(); window.sessionStorage.clear(); } catch (e) {}";
+const SELECTOR_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+const SELECTOR_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub fn create_capabilities(
+    browser: &BrowserConfig,
+    test: bool,
+) -> Result<(Capabilities, ChromeOptions)> {
     let mut capabilities = Capabilities::new();
-    let chrome_options = ChromeOptions::new(test)?;
+    let chrome_options = ChromeOptions::new(browser, test)?;
 
-    let chrome_option_json = serde_json::to_value(chrome_options)
-        .map_err(|e| {
-            eprintln!("Failed to connect process: {:?}", e);
-            anyhow!("Failed to serialize ChromeOptions: {:?}", e)
-        })?;
+    let chrome_option_json = serde_json::to_value(&chrome_options).map_err(|e| {
+        eprintln!("Failed to connect process: {:?}", e);
+        anyhow!("Failed to serialize ChromeOptions: {:?}", e)
+    })?;
 
     capabilities.insert("goog:chromeOptions".to_string(), chrome_option_json);
-    Ok(capabilities)
+    // 실패 artifact 의 console log 수집용
+    capabilities.insert(
+        "goog:loggingPrefs".to_string(),
+        serde_json::json!({ "browser": "ALL" }),
+    );
+    capabilities.insert(
+        "timeouts".to_string(),
+        serde_json::json!({ "pageLoad": browser.page_load_timeout.as_millis() as u64 }),
+    );
+    Ok((capabilities, chrome_options))
 }
 
-pub async fn create_client(url: &str, test: bool) -> Result<Arc<Client>> {
-    let caps = create_capabilities(test)?;
+// ChromeOptions 의 user-data-dir 은 세션이 살아있는 동안 유지되어야 하므로 같이 반환
+pub async fn create_client(
+    url: &str,
+    browser: &BrowserConfig,
+    test: bool,
+) -> Result<(Client, ChromeOptions)> {
+    let (caps, chrome_options) = create_capabilities(browser, test)?;
 
     let client = ClientBuilder::native()
         .capabilities(caps)
@@ -63,16 +68,21 @@ pub async fn create_client(url: &str, test: bool) -> Result<Arc<Client>> {
         .await
         .map_err(|e| {
             eprintln!("Failed to connect process: {:?}", e);
-            anyhow!("Failed to connect process: {:?}", e)
+            CrawlError::SessionCreate(format!("Failed to connect process: {:?}", e))
         })?;
 
-    Ok(Arc::new(client))
+    Ok((client, chrome_options))
 }
 
 pub async fn go_to_url(client: &Client, url: &str) -> Result<()> {
     client.goto(url).await.map_err(|e| {
         eprintln!("Failed to client goto URL({})\n {:?}", url, e);
-        anyhow!("Failed to client goto URL({})\n {:?}", url, e)
+        let detail = format!("Failed to client goto URL({})\n {:?}", url, e);
+        if is_timeout(&e) {
+            anyhow!(CrawlError::NavigationTimeout(detail))
+        } else {
+            anyhow!(detail)
+        }
     })?;
 
     Ok(())
@@ -81,7 +91,12 @@ pub async fn go_to_url(client: &Client, url: &str) -> Result<()> {
 pub async fn find_element(client: &Client, locator: Locator<'_>) -> Result<Element> {
     let element = client.find(locator).await.map_err(|e| {
         eprintln!("Failed to find element: {:?}\n {:?}", locator, e);
-        anyhow!("Failed to find element: {:?}\n {:?}", locator, e)
+        let detail = format!("Failed to find element: {:?}\n {:?}", locator, e);
+        if e.is_no_such_element() {
+            anyhow!(CrawlError::SelectorNotFound(detail))
+        } else {
+            anyhow!(detail)
+        }
     })?;
 
     Ok(element)
@@ -90,12 +105,61 @@ pub async fn find_element(client: &Client, locator: Locator<'_>) -> Result<Eleme
 pub async fn wait_element(client: &Client, locator: Locator<'_>) -> Result<Element> {
     let element = client.wait().for_element(locator).await.map_err(|e| {
         eprintln!("Failed to wait element: {:?}\n {}", locator, e);
-        anyhow!("Failed to wait element: {:?}\n {}", locator, e)
+        let detail = format!("Failed to wait element: {:?}\n {}", locator, e);
+        if e.is_no_such_element() || is_timeout(&e) {
+            anyhow!(CrawlError::SelectorNotFound(detail))
+        } else {
+            anyhow!(detail)
+        }
     })?;
 
     Ok(element)
 }
 
+// 현재 페이지에서 찾을 수 있는 첫 번째 selector. fallback 이 맞으면 로그로 남김
+pub async fn find_selector<'a>(client: &Client, selector: &'a Selector) -> Result<Locator<'a>> {
+    for (i, spec) in selector.alternatives.iter().enumerate() {
+        if client.find(spec.locator()).await.is_ok() {
+            if i > 0 {
+                println!(
+                    "Selector {} matched fallback #{}: {}",
+                    selector.key, i, spec
+                );
+            }
+            return Ok(spec.locator());
+        }
+    }
+
+    eprintln!("Failed to find selector: {}", selector.key);
+    Err(CrawlError::SelectorNotFound(format!("Failed to find selector: {}", selector.key)).into())
+}
+
+// selector 중 하나가 나타날 때까지 대기
+pub async fn wait_selector<'a>(client: &Client, selector: &'a Selector) -> Result<Locator<'a>> {
+    if let [spec] = selector.alternatives.as_slice() {
+        wait_element(client, spec.locator()).await?;
+        return Ok(spec.locator());
+    }
+
+    timeout(SELECTOR_WAIT_TIMEOUT, async {
+        loop {
+            if let Ok(locator) = find_selector(client, selector).await {
+                return locator;
+            }
+            tokio::time::sleep(SELECTOR_POLL_INTERVAL).await;
+        }
+    })
+    .await
+    .map_err(|e| {
+        eprintln!("Failed to wait selector: {}\n {:?}", selector.key, e);
+        CrawlError::SelectorNotFound(format!(
+            "Failed to wait selector: {}\n {:?}",
+            selector.key, e
+        ))
+        .into()
+    })
+}
+
 pub async fn click_element(client: &Client, locator: Locator<'_>) -> Result<()> {
     let element = find_element(client, locator).await?;
 
@@ -107,14 +171,13 @@ pub async fn click_element(client: &Client, locator: Locator<'_>) -> Result<()>
     Ok(())
 }
 
-pub async fn script_execute(client: &Client, script: &str) -> Result<()> {
-    client.execute(script, vec![]).await
-        .map_err(|e| {
-            eprintln!("Failed to execute the script: {:?}\n {:?}", script, e);
-            anyhow!("Failed to execute the script: {:?}\n {:?}", script, e)
-        })?;
-    
-    Ok(())
+pub async fn script_execute(client: &Client, script: &str) -> Result<serde_json::Value> {
+    let value = client.execute(script, vec![]).await.map_err(|e| {
+        eprintln!("Failed to execute the script: {:?}\n {:?}", script, e);
+        anyhow!("Failed to execute the script: {:?}\n {:?}", script, e)
+    })?;
+
+    Ok(value)
 }
 
 pub async fn enter_value_in_element(
@@ -132,21 +195,6 @@ pub async fn enter_value_in_element(
     Ok(())
 }
 
-pub async fn attr_element(
-    client: &Client,
-    locator: Locator<'_>,
-    attr: &str,
-) -> Result<Option<String>> {
-    let element = find_element(client, locator).await?;
-
-    let attr = element.attr(attr).await.map_err(|e| {
-        eprintln!("Failed to get attr from element: {:?}", e);
-        anyhow!("Failed to get attr from element: {:?}", e)
-    })?;
-
-    Ok(attr)
-}
-
 pub async fn text_element(client: &Client, locator: Locator<'_>) -> Result<String> {
     let element = find_element(client, locator).await?;
 
@@ -158,6 +206,13 @@ pub async fn text_element(client: &Client, locator: Locator<'_>) -> Result<Strin
     Ok(text)
 }
 
+// selector 중 찾은 요소의 text
+pub async fn text_by_selector(client: &Client, selector: &Selector) -> Result<String> {
+    let locator = find_selector(client, selector).await?;
+
+    text_element(client, locator).await
+}
+
 pub async fn wait_for_element_display_none(
     client: &Client,
     locator: Locator<'_>,
@@ -185,20 +240,680 @@ pub async fn wait_for_element_display_none(
             "Failed to wait the element within the given duration: {:?}",
             e
         );
-        anyhow!(
+        CrawlError::NavigationTimeout(format!(
             "Failed to wait the element within the given duration: {:?}",
             e
+        ))
+    })?;
+
+    Ok(())
+}
+
+pub async fn wait_for_element_aria_hidden(
+    client: &Client,
+    locator: Locator<'_>,
+    duration: Duration,
+) -> Result<()> {
+    let element = wait_element(client, locator).await?;
+
+    timeout(duration, async {
+        loop {
+            match element.attr("aria-hidden").await {
+                Ok(Some(value)) if value == "true" => {
+                    println!("Element is hidden (aria-hidden=\"true\")");
+                    break;
+                }
+                _ => {
+                    println!("Element is not hidden, retrying...");
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                }
+            }
+        }
+    })
+    .await
+    .map_err(|e| {
+        eprintln!(
+            "Failed to wait the element within the given duration: {:?}",
+            e
+        );
+        CrawlError::NavigationTimeout(format!(
+            "Failed to wait the element within the given duration: {:?}",
+            e
+        ))
+    })?;
+
+    Ok(())
+}
+
+pub async fn click_element_with_retries(
+    client: &Client,
+    locator: Locator<'_>,
+    max_attempts: u32,
+) -> Result<()> {
+    for attempt in 1..=max_attempts {
+        match click_element(client, locator).await {
+            Ok(_) => return Ok(()),
+            Err(_) => {
+                println!("Retrying to click the element (attempt {})", attempt);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+
+    eprintln!(
+        "Failed to click the element after {} attempts: {:?}",
+        max_attempts, locator
+    );
+    Err(anyhow!(
+        "Failed to click the element after {} attempts: {:?}",
+        max_attempts,
+        locator
+    ))
+}
+
+pub async fn option_index_by_text(
+    client: &Client,
+    select_locator: Locator<'_>,
+    text: &str,
+) -> Result<usize> {
+    let select = find_element(client, select_locator).await?;
+
+    let options = select.find_all(Locator::Css("option")).await.map_err(|e| {
+        eprintln!("Failed to find options: {:?}\n {:?}", select_locator, e);
+        anyhow!("Failed to find options: {:?}\n {:?}", select_locator, e)
+    })?;
+
+    for (index, option) in options.iter().enumerate() {
+        if option
+            .text()
+            .await
+            .is_ok_and(|option_text| option_text == text)
+        {
+            return Ok(index);
+        }
+    }
+
+    eprintln!(
+        "Option with text '{}' not found: {:?}",
+        text, select_locator
+    );
+    Err(anyhow!(
+        "Option with text '{}' not found: {:?}",
+        text,
+        select_locator
+    ))
+}
+
+// selector 로 찾은 입력 요소에 값을 넣고 change 이벤트 발생 (readonly datepicker / select)
+pub async fn set_value_by_selector(
+    client: &Client,
+    selector: &Selector,
+    value: &str,
+) -> Result<()> {
+    let element = find_element(client, find_selector(client, selector).await?).await?;
+    let script = "arguments[0].value = arguments[1]; arguments[0].dispatchEvent(new Event('change', { bubbles: true }));";
+
+    client
+        .execute(
+            script,
+            vec![serde_json::to_value(&element)?, serde_json::json!(value)],
         )
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to set value: {}\n {:?}", selector.key, e);
+            anyhow!("Failed to set value: {}\n {:?}", selector.key, e)
+        })?;
+
+    Ok(())
+}
+
+// selector 로 찾은 요소(tbody 등) 안 행들의 셀 텍스트. 셀이 없는 행(헤더 등)은 제외
+pub async fn table_rows_by_selector(
+    client: &Client,
+    selector: &Selector,
+) -> Result<Vec<Vec<String>>> {
+    let element = find_element(client, find_selector(client, selector).await?).await?;
+    let script = "return Array.from(arguments[0].querySelectorAll('tr')).map(tr => Array.from(tr.querySelectorAll('td')).map(td => (td.innerText || td.textContent || '').trim())).filter(cells => cells.length > 0);";
+
+    let value = client
+        .execute(script, vec![serde_json::to_value(&element)?])
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to read table rows: {}\n {:?}", selector.key, e);
+            anyhow!("Failed to read table rows: {}\n {:?}", selector.key, e)
+        })?;
+    serde_json::from_value(value).map_err(|e| {
+        eprintln!("Failed to parse table rows: {}\n {:?}", selector.key, e);
+        anyhow!("Failed to parse table rows: {}\n {:?}", selector.key, e)
+    })
+}
+
+// 이전 다운로드 파일 제거
+pub fn clear_download_dir(dir: &Path) -> Result<()> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        eprintln!("Failed to read download dir: {:?}\n {:?}", dir, e);
+        anyhow!("Failed to read download dir: {:?}\n {:?}", dir, e)
     })?;
 
+    for entry in entries.flatten() {
+        let _ = std::fs::remove_file(entry.path());
+    }
+
+    Ok(())
+}
+
+// 다운로드 완료 대기. 임시 파일(.crdownload)이 사라지고 크기가 변하지 않으면 완료로 판단
+pub async fn wait_for_download(dir: &Path, duration: Duration) -> Result<PathBuf> {
+    timeout(duration, async {
+        let mut last: Option<(PathBuf, u64)> = None;
+        loop {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            let files = entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .collect::<Vec<PathBuf>>();
+
+            let in_progress = files.iter().any(|path| {
+                path.extension()
+                    .is_some_and(|ext| ext == "crdownload" || ext == "tmp")
+            });
+            let Some(file) = files.into_iter().next() else {
+                continue;
+            };
+            if in_progress {
+                last = None;
+                continue;
+            }
+
+            let size = std::fs::metadata(&file).map(|m| m.len()).unwrap_or(0);
+            match &last {
+                Some((path, last_size)) if *path == file && *last_size == size && size > 0 => {
+                    println!("Download finished: {:?}", file);
+                    return file;
+                }
+                _ => last = Some((file, size)),
+            }
+        }
+    })
+    .await
+    .map_err(|e| {
+        eprintln!(
+            "Failed to wait the download within the given duration: {:?}",
+            e
+        );
+        anyhow!(CrawlError::NavigationTimeout(format!(
+            "Failed to wait the download within the given duration: {:?}",
+            e
+        )))
+    })
+}
+
+// 점검 안내 페이지 문구
+const MAINTENANCE_KEYWORDS: &[&str] = &["시스템 점검", "서비스 점검", "점검 중", "점검중"];
+
+// 현재 페이지가 점검 안내 페이지면 SiteMaintenance
+pub async fn ensure_not_in_maintenance(client: &Client) -> Result<()> {
+    let text = script_execute(
+        client,
+        "return document.body ? document.body.innerText : '';",
+    )
+    .await?;
+    let text = text.as_str().unwrap_or_default();
+
+    if let Some(keyword) = MAINTENANCE_KEYWORDS
+        .iter()
+        .find(|keyword| text.contains(*keyword))
+    {
+        eprintln!("Site is under maintenance: {}", keyword);
+        return Err(CrawlError::SiteMaintenance(keyword.to_string()).into());
+    }
+
+    Ok(())
+}
+
+// 열린 alert / confirm dialog 의 문구. 없으면 None
+pub async fn pending_dialog_text(client: &Client) -> Result<Option<String>> {
+    match client.get_alert_text().await {
+        Ok(text) => Ok(Some(text)),
+        Err(e) if e.is_no_such_alert() => Ok(None),
+        Err(e) => {
+            eprintln!("Failed to get dialog text: {:?}", e);
+            Err(anyhow!("Failed to get dialog text: {:?}", e))
+        }
+    }
+}
+
+// 명령 도중 열린 dialog 를 chromedriver 가 닫고 돌려준 오류면 그 문구
+pub fn unexpected_alert_text(e: &CmdError) -> Option<String> {
+    match e {
+        CmdError::Standard(e) if e.error == ErrorStatus::UnexpectedAlertOpen => Some(
+            e.data
+                .as_ref()
+                .and_then(|data| data["text"].as_str())
+                .unwrap_or(&e.message)
+                .to_string(),
+        ),
+        _ => None,
+    }
+}
+
+// 열린 dialog 가 있으면 문구를 읽고 accept / dismiss. 없으면 None
+pub async fn handle_dialog(client: &Client, action: DialogAction) -> Result<Option<String>> {
+    let Some(text) = pending_dialog_text(client).await? else {
+        return Ok(None);
+    };
+
+    let result = match action {
+        DialogAction::Accept => client.accept_alert().await,
+        DialogAction::Dismiss => client.dismiss_alert().await,
+    };
+    match result {
+        Ok(_) => {}
+        // 읽는 사이 페이지 스크립트가 닫은 경우
+        Err(e) if e.is_no_such_alert() => {}
+        Err(e) => {
+            eprintln!("Failed to {:?} dialog: {:?}", action, e);
+            return Err(anyhow!("Failed to {:?} dialog: {:?}", action, e));
+        }
+    }
+
+    println!("Dialog handled ({:?}): {}", action, text);
+    Ok(Some(text))
+}
+
+// 열린 dialog 가 있으면 처리 후 UnexpectedDialog
+pub async fn ensure_no_dialog(client: &Client, action: DialogAction) -> Result<()> {
+    match handle_dialog(client, action).await? {
+        Some(text) => Err(CrawlError::UnexpectedDialog(text).into()),
+        None => Ok(()),
+    }
+}
+
+// 작업 중 dialog 가 뜨면 처리하고 작업을 중단. dialog 문구는 UnexpectedDialog 로 전달
+pub async fn with_dialog_guard<T, F>(client: &Client, action: DialogAction, task: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    let watch_dialog = async {
+        loop {
+            if let Ok(Some(text)) = handle_dialog(client, action).await {
+                return text;
+            }
+            tokio::time::sleep(DIALOG_POLL_INTERVAL).await;
+        }
+    };
+
+    tokio::select! {
+        result = task => result,
+        text = watch_dialog => {
+            eprintln!("Dialog opened while waiting: {}", text);
+            Err(CrawlError::UnexpectedDialog(text).into())
+        }
+    }
+}
+
+// ChromeDriver 의 browser log 조회 명령. W3C 표준이 아니라 별도 endpoint 사용
+#[derive(Debug)]
+struct BrowserLogCommand;
+
+impl WebDriverCompatibleCommand for BrowserLogCommand {
+    fn endpoint(&self, base_url: &Url, session_id: Option<&str>) -> Result<Url, ParseError> {
+        base_url.join(&format!(
+            "session/{}/se/log",
+            session_id.unwrap_or_default()
+        ))
+    }
+
+    fn method_and_body(&self, _request_url: &Url) -> (Method, Option<String>) {
+        (Method::POST, Some(r#"{"type":"browser"}"#.to_string()))
+    }
+}
+
+// Chrome DevTools Protocol 명령 (chromedriver 확장)
+#[derive(Debug)]
+struct CdpCommand {
+    cmd: &'static str,
+    params: serde_json::Value,
+}
+
+impl WebDriverCompatibleCommand for CdpCommand {
+    fn endpoint(&self, base_url: &Url, session_id: Option<&str>) -> Result<Url, ParseError> {
+        base_url.join(&format!(
+            "session/{}/goog/cdp/execute",
+            session_id.unwrap_or_default()
+        ))
+    }
+
+    fn method_and_body(&self, _request_url: &Url) -> (Method, Option<String>) {
+        let body = serde_json::json!({ "cmd": self.cmd, "params": self.params });
+        (Method::POST, Some(body.to_string()))
+    }
+}
+
+async fn cdp_execute(client: &Client, cmd: &'static str, params: serde_json::Value) -> Result<()> {
+    client
+        .issue_cmd(CdpCommand { cmd, params })
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to execute CDP command {}: {:?}", cmd, e);
+            anyhow!("Failed to execute CDP command {}: {:?}", cmd, e)
+        })?;
+
     Ok(())
 }
 
+// 마지막 조회 이후 쌓인 browser console log
+pub async fn browser_console_logs(client: &Client) -> Result<serde_json::Value> {
+    client.issue_cmd(BrowserLogCommand).await.map_err(|e| {
+        eprintln!("Failed to get browser console logs: {:?}", e);
+        anyhow!("Failed to get browser console logs: {:?}", e)
+    })
+}
+
+// WebDriver 명령 또는 wait 조건의 타임아웃 여부
+fn is_timeout(e: &CmdError) -> bool {
+    e.is_timeout() || e.is_script_timeout() || matches!(e, CmdError::WaitTimeout)
+}
+
 pub async fn clean_client(client: &Client) -> Result<()> {
-    client.delete_all_cookies().await.map_err(|e| {
-        eprintln!("Failed to delete client cookies: {:?}", e);
-        anyhow!("Failed to delete client cookies: {:?}", e)
-    })?;
+    // 남아 있는 dialog 는 이후 모든 명령을 실패시킴
+    handle_dialog(client, DialogAction::Dismiss).await?;
+
+    // 현재 페이지 origin 의 storage
+    script_execute(client, CLEAR_WEB_STORAGE_SCRIPT).await?;
+
+    // 세션은 다른 고객 계정에 재사용되므로 현재 origin 뿐 아니라 모든 cookie 를 지움
+    cdp_execute(client, "Network.clearBrowserCookies", serde_json::json!({})).await?;
+
+    // 등록된 사이트(pp, kepco_on 등) origin 의 local / session storage, IndexedDB 등
+    for (_, site) in sites().iter() {
+        let origin = site.base_url.origin().ascii_serialization();
+        cdp_execute(
+            client,
+            "Storage.clearDataForOrigin",
+            serde_json::json!({ "origin": origin, "storageTypes": "all" }),
+        )
+        .await?;
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+// 풀에서 관리되는 브라우저 세션. ChromeOptions 는 temp profile 수명 유지를 위해 보관
+pub struct PooledSession {
+    client: Client,
+    test: bool,
+    chrome_options: ChromeOptions,
+}
+
+impl PooledSession {
+    async fn close(self) {
+        if let Err(e) = self.client.close().await {
+            eprintln!("Failed to close pooled session: {:?}", e);
+        }
+    }
+}
+
+// WebDriver 세션 풀 (checkout / checkin)
+pub struct SessionPool {
+    url: String,
+    config: PoolConfig,
+    browser: BrowserConfig,
+    idle: Mutex<Vec<PooledSession>>,
+    permits: Arc<Semaphore>,
+    driver: watch::Receiver<DriverStatus>,
+}
+
+// checkout 된 세션. drop 시 자동으로 풀에 반환
+pub struct SessionGuard {
+    session: Option<PooledSession>,
+    permit: Option<OwnedSemaphorePermit>,
+    pool: Arc<SessionPool>,
+}
+
+impl Deref for SessionGuard {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        &self
+            .session
+            .as_ref()
+            .expect("SessionGuard used after release")
+            .client
+    }
+}
+
+impl SessionGuard {
+    // 이 세션의 Chrome 다운로드 디렉토리
+    pub fn download_dir(&self) -> &Path {
+        self.session
+            .as_ref()
+            .expect("SessionGuard used after release")
+            .chrome_options
+            .download_dir()
+    }
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        if let Some(session) = self.session.take() {
+            let pool = Arc::clone(&self.pool);
+            // 반환이 끝날 때까지 permit 을 유지해야 max_size 를 넘지 않음
+            let permit = self.permit.take();
+            tokio::spawn(async move {
+                pool.checkin(session).await;
+                drop(permit);
+            });
+        }
+    }
+}
+
+impl SessionPool {
+    pub fn new(
+        url: &str,
+        config: PoolConfig,
+        browser: BrowserConfig,
+        driver: watch::Receiver<DriverStatus>,
+    ) -> Arc<Self> {
+        Arc::new(SessionPool {
+            url: url.to_string(),
+            browser,
+            permits: Arc::new(Semaphore::new(config.max_size)),
+            idle: Mutex::new(Vec::with_capacity(config.max_size)),
+            config,
+            driver,
+        })
+    }
+
+    pub async fn checkout(self: &Arc<Self>, test: bool) -> Result<SessionGuard> {
+        // chromedriver 재시작 중이면 ready 가 될 때까지 대기
+        let mut driver = self.driver.clone();
+        timeout(
+            self.config.checkout_timeout,
+            driver.wait_for(|status| status.state == DriverState::Ready),
+        )
+        .await
+        .map_err(|_| {
+            eprintln!("Timed out waiting for ChromeDriver to become ready");
+            CrawlError::DriverStart(
+                "Timed out waiting for ChromeDriver to become ready".to_string(),
+            )
+        })?
+        .map_err(|e| {
+            CrawlError::DriverStart(format!("ChromeDriver supervisor is gone: {:?}", e))
+        })?;
+
+        let permit = timeout(
+            self.config.checkout_timeout,
+            Arc::clone(&self.permits).acquire_owned(),
+        )
+        .await
+        .map_err(|_| {
+            eprintln!(
+                "Timed out waiting for a free session ({} max)",
+                self.config.max_size
+            );
+            anyhow!(
+                "Timed out waiting for a free session ({} max)",
+                self.config.max_size
+            )
+        })?
+        .map_err(|e| anyhow!("Session pool is closed: {:?}", e))?;
+
+        // idle 세션이 죽어 있으면 새 세션으로 교체. 새 세션도 계속 죽으면 chromedriver 이상으로 보고 포기
+        let mut replaced = 0;
+        loop {
+            let session = match self.take_idle(test).await {
+                Some(session) => session,
+                None => {
+                    replaced += 1;
+                    self.create_session(test).await?
+                }
+            };
+
+            if is_healthy(&session.client).await {
+                return Ok(SessionGuard {
+                    session: Some(session),
+                    permit: Some(permit),
+                    pool: Arc::clone(self),
+                });
+            }
+
+            session.close().await;
+            if replaced >= SESSION_REPLACE_ATTEMPTS {
+                eprintln!(
+                    "New sessions keep failing health check ({} attempts)",
+                    replaced
+                );
+                return Err(CrawlError::DriverStart(format!(
+                    "New sessions keep failing health check ({} attempts)",
+                    replaced
+                ))
+                .into());
+            }
+            eprintln!("Discarding crashed session, replacing it");
+        }
+    }
+
+    async fn checkin(&self, session: PooledSession) {
+        let cleaned = async {
+            clean_client(&session.client).await?;
+            // 다른 요청에 이전 청구서 파일이 남지 않도록 정리
+            clear_download_dir(session.chrome_options.download_dir())?;
+            go_to_url(&session.client, "about:blank").await
+        }
+        .await;
+
+        match cleaned {
+            Ok(_) => self.idle.lock().await.push(session),
+            Err(e) => {
+                eprintln!("Failed to clean session on checkin, closing it: {:?}", e);
+                session.close().await;
+            }
+        }
+    }
+
+    // test 플래그가 맞는 idle 세션을 꺼냄. 없다면 다른 플래그의 세션 하나를 닫아 max_size 유지
+    async fn take_idle(&self, test: bool) -> Option<PooledSession> {
+        let mut idle = self.idle.lock().await;
+
+        if let Some(index) = idle.iter().position(|session| session.test == test) {
+            return Some(idle.swap_remove(index));
+        }
+
+        if let Some(evicted) = idle.pop() {
+            drop(idle);
+            evicted.close().await;
+        }
+
+        None
+    }
+
+    async fn create_session(&self, test: bool) -> Result<PooledSession> {
+        let (client, chrome_options) = create_client(&self.url, &self.browser, test).await?;
+
+        Ok(PooledSession {
+            client,
+            test,
+            chrome_options,
+        })
+    }
+
+    // idle 세션 health check 후 죽은 세션 교체, min_size 까지 채움
+    pub async fn maintain(&self) {
+        if self.driver.borrow().state != DriverState::Ready {
+            return;
+        }
+
+        // 점검 중인 세션이 checkout 가능한 자리를 차지하도록 세션마다 permit 을 잡음.
+        // permit 이 없으면 모두 사용 중이므로 이번 점검은 건너뜀
+        let checks = self.idle.lock().await.len();
+        for _ in 0..checks {
+            let Ok(_permit) = Arc::clone(&self.permits).try_acquire_owned() else {
+                break;
+            };
+            let Some(session) = self.idle.lock().await.pop() else {
+                break;
+            };
+
+            if is_healthy(&session.client).await {
+                self.idle.lock().await.insert(0, session);
+            } else {
+                eprintln!("Idle session failed health check, closing it");
+                session.close().await;
+            }
+        }
+
+        // 만드는 동안 checkout 이 따로 세션을 만들어 max_size 를 넘지 않도록 permit 을 잡고,
+        // idle 에 넣은 뒤 반납
+        loop {
+            let Ok(_permit) = Arc::clone(&self.permits).try_acquire_owned() else {
+                break;
+            };
+            let (idle_total, idle_default) = {
+                let idle = self.idle.lock().await;
+                (idle.len(), idle.iter().filter(|s| !s.test).count())
+            };
+            // 방금 잡은 permit 은 제외
+            let checked_out = self.config.max_size - self.permits.available_permits() - 1;
+
+            if idle_default >= self.config.min_size
+                || idle_total + checked_out >= self.config.max_size
+            {
+                break;
+            }
+
+            match self.create_session(false).await {
+                Ok(session) => self.idle.lock().await.push(session),
+                Err(e) => {
+                    eprintln!("Failed to warm up pooled session: {:?}", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    pub fn spawn_maintenance(self: &Arc<Self>) -> JoinHandle<()> {
+        let pool = Arc::clone(self);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(pool.config.health_check_interval);
+            loop {
+                interval.tick().await;
+                pool.maintain().await;
+            }
+        })
+    }
+}
+
+async fn is_healthy(client: &Client) -> bool {
+    matches!(
+        timeout(SESSION_HEALTH_CHECK_TIMEOUT, client.current_url()).await,
+        Ok(Ok(_))
+    )
+}
@@ -0,0 +1,287 @@
+use crate::models::{
+    auth::api_keys::ClientIdentity,
+    error::response_errors_def::ErrorResponseCode,
+    handler::jobs::{
+        crawl_jobs::{CrawlJob, CrawlKind, JobStatus},
+        progress::ProgressEvent,
+    },
+};
+use crate::utils::progress::ProgressReporter;
+use chrono::Utc;
+use dashmap::DashMap;
+use serde_json::Value;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::{AbortHandle, JoinHandle};
+use uuid::Uuid;
+
+const JOB_TTL: Duration = Duration::from_secs(60 * 60);
+const JOB_CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+const JOB_EVENT_CAPACITY: usize = 64;
+
+struct JobEntry {
+    job: CrawlJob,
+    abort: Option<AbortHandle>,
+    events: broadcast::Sender<ProgressEvent>,
+}
+
+// 비동기 크롤링 job 저장소. 완료된 job 은 JOB_TTL 이후 정리
+pub struct JobStore {
+    jobs: DashMap<Uuid, JobEntry>,
+}
+
+impl JobStore {
+    pub fn new() -> Arc<Self> {
+        Arc::new(JobStore {
+            jobs: DashMap::new(),
+        })
+    }
+
+    pub fn submit<F, Fut>(
+        self: &Arc<Self>,
+        kind: CrawlKind,
+        owner: ClientIdentity,
+        task: F,
+    ) -> CrawlJob
+    where
+        F: FnOnce(ProgressReporter) -> Fut,
+        Fut: Future<Output = Result<Value, ErrorResponseCode>> + Send + 'static,
+    {
+        let job = CrawlJob::new(kind, owner);
+        let id = job.id;
+        let (events, _) = broadcast::channel(JOB_EVENT_CAPACITY);
+        self.jobs.insert(
+            id,
+            JobEntry {
+                job: job.clone(),
+                abort: None,
+                events,
+            },
+        );
+
+        let reporter = {
+            let store = Arc::clone(self);
+            ProgressReporter::new(move |event| store.publish(id, event))
+        };
+        let future = task(reporter);
+        let store = Arc::clone(self);
+
+        let join = tokio::spawn(async move {
+            store.update(id, |job| {
+                if job.status == JobStatus::Queued {
+                    job.status = JobStatus::Running;
+                }
+            });
+
+            let result = future.await;
+
+            let event = match &result {
+                Ok(value) => ProgressEvent::Finished {
+                    rows: value.as_array().map(|rows| rows.len()),
+                },
+                Err(e) => ProgressEvent::Failed { error: e.to_json() },
+            };
+
+            let mut finished = false;
+            store.update(id, |job| {
+                // 취소된 job 은 결과를 덮어쓰지 않음
+                if job.status.is_finished() {
+                    return;
+                }
+                match result {
+                    Ok(value) => {
+                        job.status = JobStatus::Succeeded;
+                        job.result = Some(value);
+                    }
+                    Err(e) => {
+                        job.status = JobStatus::Failed;
+                        job.error = Some(e.to_json());
+                    }
+                }
+                finished = true;
+            });
+
+            if finished {
+                store.publish(id, event);
+            }
+        });
+
+        if let Some(mut entry) = self.jobs.get_mut(&id) {
+            entry.abort = Some(join.abort_handle());
+        }
+
+        job
+    }
+
+    // 다른 클라이언트의 job 은 없는 job 과 같이 None
+    pub fn get(&self, id: &Uuid, client: &ClientIdentity) -> Option<CrawlJob> {
+        self.jobs
+            .get(id)
+            .filter(|entry| entry.job.visible_to(client))
+            .map(|entry| entry.job.clone())
+    }
+
+    // 현재 상태와 이후 진행 이벤트 구독
+    pub fn subscribe(
+        &self,
+        id: &Uuid,
+        client: &ClientIdentity,
+    ) -> Option<(CrawlJob, broadcast::Receiver<ProgressEvent>)> {
+        self.jobs
+            .get(id)
+            .filter(|entry| entry.job.visible_to(client))
+            .map(|entry| (entry.job.clone(), entry.events.subscribe()))
+    }
+
+    // 실행 중이면 task 를 abort. task 가 drop 되면서 SessionGuard 가 세션을 풀에 반환
+    pub fn cancel(&self, id: &Uuid, client: &ClientIdentity) -> Option<CrawlJob> {
+        let job = {
+            let mut entry = self
+                .jobs
+                .get_mut(id)
+                .filter(|entry| entry.job.visible_to(client))?;
+
+            if entry.job.status.is_finished() {
+                return Some(entry.job.clone());
+            }
+
+            if let Some(abort) = entry.abort.take() {
+                abort.abort();
+            }
+            entry.job.status = JobStatus::Cancelled;
+            entry.job.updated_at = Utc::now();
+            entry.job.clone()
+        };
+
+        self.publish(*id, ProgressEvent::Cancelled);
+        Some(job)
+    }
+
+    pub fn spawn_cleanup(self: &Arc<Self>) -> JoinHandle<()> {
+        let store = Arc::clone(self);
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(JOB_CLEANUP_INTERVAL);
+            loop {
+                interval.tick().await;
+                let now = Utc::now();
+                store.jobs.retain(|_, entry| {
+                    !entry.job.status.is_finished()
+                        || (now - entry.job.updated_at).to_std().unwrap_or_default() < JOB_TTL
+                });
+            }
+        })
+    }
+
+    // 마지막 이벤트를 job 에 기록하고 구독자에게 전달
+    fn publish(&self, id: Uuid, event: ProgressEvent) {
+        if let Some(mut entry) = self.jobs.get_mut(&id) {
+            entry.job.progress = Some(event.clone());
+            entry.job.updated_at = Utc::now();
+            // 구독자가 없으면 Err 이지만 무시해도 됨
+            let _ = entry.events.send(event);
+        }
+    }
+
+    fn update(&self, id: Uuid, f: impl FnOnce(&mut CrawlJob)) {
+        if let Some(mut entry) = self.jobs.get_mut(&id) {
+            f(&mut entry.job);
+            entry.job.updated_at = Utc::now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::auth::api_keys::{ApiKeyConfig, ApiKeyFile, ClientLimits};
+    use crate::utils::auth::ApiKeyStore;
+    use tokio::sync::oneshot;
+
+    fn clients(max_concurrent: usize) -> Arc<ApiKeyStore> {
+        ApiKeyStore::new(ApiKeyConfig {
+            clients: ApiKeyFile::from_list("erp:erp-key").unwrap(),
+            defaults: ClientLimits {
+                max_concurrent,
+                daily_quota: 10,
+            },
+        })
+        .unwrap()
+    }
+
+    fn client(name: &str, admin: bool) -> ClientIdentity {
+        ClientIdentity {
+            name: name.to_string(),
+            admin,
+        }
+    }
+
+    async fn wait_finished(store: &JobStore, id: &Uuid, owner: &ClientIdentity) {
+        for _ in 0..100 {
+            if store
+                .get(id, owner)
+                .is_some_and(|job| job.status.is_finished())
+            {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("job did not finish");
+    }
+
+    #[tokio::test]
+    async fn running_job_keeps_its_crawl_permit() {
+        let clients = clients(1);
+        let store = JobStore::new();
+        let (finish, finished) = oneshot::channel::<()>();
+
+        // submit_crawl_job_handler 와 같이 permit 을 task 로 넘김
+        let permit = clients.acquire_crawl("erp-key").ok().expect("crawl permit");
+        let owner = client("erp", false);
+        let job = store.submit(CrawlKind::PpUserInfo, owner.clone(), move |_| async move {
+            let _permit = permit;
+            let _ = finished.await;
+            Ok(Value::Null)
+        });
+
+        let busy = clients.acquire_crawl("erp-key").err().unwrap();
+        assert_eq!(
+            busy.code,
+            ErrorResponseCode::TOO_MANY_CONCURRENT_CRAWLS.code
+        );
+        assert_eq!(busy.status_code, axum::http::StatusCode::TOO_MANY_REQUESTS);
+
+        finish.send(()).unwrap();
+        wait_finished(&store, &job.id, &owner).await;
+        assert!(clients.acquire_crawl("erp-key").is_ok());
+    }
+
+    #[tokio::test]
+    async fn jobs_are_visible_to_their_owner_and_admins_only() {
+        let store = JobStore::new();
+        let owner = client("erp", false);
+        let other = client("billing", false);
+        let admin = client("ops", true);
+
+        let job = store.submit(CrawlKind::PpUserInfo, owner.clone(), |_| {
+            std::future::pending::<Result<Value, ErrorResponseCode>>()
+        });
+
+        assert!(store.get(&job.id, &other).is_none());
+        assert!(store.subscribe(&job.id, &other).is_none());
+        assert!(store.cancel(&job.id, &other).is_none());
+        assert!(store.get(&job.id, &owner).is_some());
+        assert!(store.subscribe(&job.id, &owner).is_some());
+        assert!(store.get(&job.id, &admin).is_some());
+
+        let cancelled = store.cancel(&job.id, &admin).unwrap();
+        assert_eq!(cancelled.status, JobStatus::Cancelled);
+        assert_eq!(cancelled.owner, owner);
+        assert_eq!(
+            store.get(&job.id, &owner).map(|job| job.status),
+            Some(JobStatus::Cancelled)
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use crate::models::handler::jobs::progress::ProgressEvent;
+use std::sync::Arc;
+
+// 크롤러가 진행 상황을 보고하는 통로. 동기 핸들러에서는 none() 으로 무시
+#[derive(Clone, Default)]
+pub struct ProgressReporter {
+    sink: Option<Arc<dyn Fn(ProgressEvent) + Send + Sync>>,
+}
+
+impl ProgressReporter {
+    pub fn new(sink: impl Fn(ProgressEvent) + Send + Sync + 'static) -> Self {
+        ProgressReporter {
+            sink: Some(Arc::new(sink)),
+        }
+    }
+
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn report(&self, event: ProgressEvent) {
+        if let Some(sink) = &self.sink {
+            sink(event);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::models::driver::selectors::{
+    DEFAULT_SELECTORS, DEFAULT_SELECTORS_RELOAD_INTERVAL_SECS, Selector, SelectorConfig,
+    SelectorFile, SelectorKey,
+};
+use crate::models::error::crawl_errors::CrawlError;
+use anyhow::{Result, anyhow};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, OnceLock, RwLock};
+use std::time::{Duration, SystemTime};
+use tokio::task::JoinHandle;
+
+static REGISTRY: OnceLock<Arc<SelectorRegistry>> = OnceLock::new();
+
+// init 전에 조회되면 빌드에 포함된 기본 selector 사용
+static DEFAULT_REGISTRY: LazyLock<Arc<SelectorRegistry>> = LazyLock::new(|| {
+    let file = SelectorFile::parse(DEFAULT_SELECTORS).expect("embedded selectors.toml is invalid");
+    Arc::new(SelectorRegistry {
+        path: None,
+        reload_interval: Duration::from_secs(DEFAULT_SELECTORS_RELOAD_INTERVAL_SECS),
+        current: RwLock::new(Arc::new(file)),
+        modified: RwLock::new(None),
+    })
+});
+
+// 사이트 selector 저장소. 파일이 바뀌면 주기적으로 다시 읽음
+pub struct SelectorRegistry {
+    path: Option<PathBuf>,
+    reload_interval: Duration,
+    current: RwLock<Arc<SelectorFile>>,
+    modified: RwLock<Option<SystemTime>>,
+}
+
+impl SelectorRegistry {
+    pub fn from_config(config: &SelectorConfig) -> Result<Arc<Self>> {
+        Self::load(config.path.clone(), config.reload_interval)
+    }
+
+    pub fn load(path: PathBuf, reload_interval: Duration) -> Result<Arc<Self>> {
+        let (file, modified) = if path.exists() {
+            read_selector_file(&path, &embedded_selectors())?
+        } else {
+            println!(
+                "Selector file {} not found, using embedded selectors",
+                path.display()
+            );
+            (SelectorFile::parse(DEFAULT_SELECTORS)?, None)
+        };
+
+        for (site, selectors) in &file.sites {
+            println!("Selectors loaded: {} (version {})", site, selectors.version);
+        }
+
+        Ok(Arc::new(SelectorRegistry {
+            path: Some(path),
+            reload_interval,
+            current: RwLock::new(Arc::new(file)),
+            modified: RwLock::new(modified),
+        }))
+    }
+
+    pub fn snapshot(&self) -> Arc<SelectorFile> {
+        Arc::clone(&self.current.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    pub fn get(&self, key: SelectorKey) -> Result<Selector> {
+        self.snapshot().get(key).ok_or_else(|| {
+            eprintln!("Selector is not registered: {}", key);
+            anyhow!(CrawlError::SelectorNotFound(format!(
+                "selector is not registered: {}",
+                key
+            )))
+        })
+    }
+
+    // 파일 수정 시각이 바뀌었으면 다시 읽음. 잘못된 파일이나 코드가 사용하는 항목이 빠진
+    // 파일이면 기존 selector 유지
+    pub fn reload_if_changed(&self) -> Result<bool> {
+        let Some(path) = &self.path else {
+            return Ok(false);
+        };
+        let Ok(modified) = std::fs::metadata(path).and_then(|meta| meta.modified()) else {
+            return Ok(false);
+        };
+        if *self.modified.read().unwrap_or_else(|e| e.into_inner()) == Some(modified) {
+            return Ok(false);
+        }
+
+        let (file, modified) = read_selector_file(path, &embedded_selectors())?;
+        for (site, selectors) in &file.sites {
+            println!(
+                "Selectors reloaded: {} (version {})",
+                site, selectors.version
+            );
+        }
+        *self.current.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(file);
+        *self.modified.write().unwrap_or_else(|e| e.into_inner()) = modified;
+
+        Ok(true)
+    }
+
+    pub fn spawn_reload(self: &Arc<Self>) -> JoinHandle<()> {
+        let registry = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(registry.reload_interval);
+            loop {
+                interval.tick().await;
+                if let Err(e) = registry.reload_if_changed() {
+                    eprintln!("Failed to reload selectors, keeping previous ones: {:?}", e);
+                }
+            }
+        })
+    }
+}
+
+// 빌드에 포함된 selectors.toml. 코드가 사용하는 항목은 모두 여기에 등록되어 있음
+fn embedded_selectors() -> Arc<SelectorFile> {
+    DEFAULT_REGISTRY.snapshot()
+}
+
+// required 의 항목이 하나라도 빠진 파일은 거부
+fn read_selector_file(
+    path: &Path,
+    required: &SelectorFile,
+) -> Result<(SelectorFile, Option<SystemTime>)> {
+    let modified = std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok();
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read selector file {}: {:?}", path.display(), e))?;
+    let file = SelectorFile::parse(&text)
+        .map_err(|e| anyhow!("Invalid selector file {}: {:?}", path.display(), e))?;
+
+    let missing = file.missing_keys(required);
+    if !missing.is_empty() {
+        return Err(anyhow!(
+            "Selector file {} is missing required selectors: {}",
+            path.display(),
+            missing.join(", ")
+        ));
+    }
+
+    Ok((file, modified))
+}
+
+// 서버 시작 시 한 번 등록
+pub fn init_selectors(registry: Arc<SelectorRegistry>) {
+    if REGISTRY.set(registry).is_err() {
+        eprintln!("Selector registry is already initialized");
+    }
+}
+
+pub fn selector_registry() -> Arc<SelectorRegistry> {
+    Arc::clone(REGISTRY.get().unwrap_or(&DEFAULT_REGISTRY))
+}
+
+pub fn selector(key: SelectorKey) -> Result<Selector> {
+    selector_registry().get(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::driver::selectors::SelectorFile;
+    use std::fs::File;
+
+    const NOTICE_POPUP: SelectorKey = SelectorKey::new("pp", "intro", "notice_popup");
+
+    // 수정 시각을 바꿔 reload 대상이 되게 함
+    fn rewrite(path: &Path, text: &str, seconds: u64) {
+        std::fs::write(path, text).unwrap();
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds))
+            .unwrap();
+    }
+
+    #[test]
+    fn reload_keeps_the_previous_registry_when_keys_are_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("selectors.toml");
+        rewrite(&path, DEFAULT_SELECTORS, 1);
+
+        let registry = SelectorRegistry::load(path.clone(), Duration::from_secs(1)).unwrap();
+        let original = registry.get(NOTICE_POPUP).unwrap().alternatives;
+
+        // parse 는 되지만 pp.intro 페이지 외 항목이 모두 빠진 파일
+        let mut partial = SelectorFile::parse(DEFAULT_SELECTORS).unwrap();
+        partial.sites.retain(|site, _| site == "pp");
+        if let Some(pp) = partial.sites.get_mut("pp") {
+            pp.pages.retain(|page, _| page == "intro");
+        }
+        rewrite(&path, &toml::to_string(&partial).unwrap(), 2);
+
+        assert!(registry.reload_if_changed().is_err());
+        assert_eq!(registry.get(NOTICE_POPUP).unwrap().alternatives, original);
+        assert!(
+            registry
+                .get(SelectorKey::new("kepco_on", "login", "user_id"))
+                .is_ok()
+        );
+
+        // 항목이 모두 있으면 새 파일로 교체
+        let changed = DEFAULT_SELECTORS.replace("id:notice_auto_popup", "id:notice_popup_v2");
+        rewrite(&path, &changed, 3);
+
+        assert!(registry.reload_if_changed().unwrap());
+        assert_eq!(
+            registry.get(NOTICE_POPUP).unwrap().alternatives[0].value,
+            "notice_popup_v2"
+        );
+        assert!(!registry.reload_if_changed().unwrap());
+    }
+
+    #[test]
+    fn load_rejects_a_file_missing_required_selectors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("selectors.toml");
+        rewrite(&path, "[sites.pp]\nversion = \"1\"\n", 1);
+
+        assert!(SelectorRegistry::load(path, Duration::from_secs(1)).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::models::driver::sites::{DEFAULT_SITES, SiteConfig, SiteFile, SitePage, Sites};
+use anyhow::{Result, anyhow};
+use std::sync::{Arc, LazyLock, OnceLock};
+
+static SITES: OnceLock<Arc<Sites>> = OnceLock::new();
+
+// init 전에 조회되면 빌드에 포함된 기본 주소 사용
+static DEFAULT_SITES_CONFIG: LazyLock<Arc<Sites>> = LazyLock::new(|| {
+    let file = SiteFile::parse(DEFAULT_SITES).expect("embedded sites.toml is invalid");
+    Arc::new(Sites::resolve(file).expect("embedded sites.toml is invalid"))
+});
+
+// 기본값 < sites_path 파일 < base_urls 순으로 적용
+pub fn load_sites(config: &SiteConfig) -> Result<Arc<Sites>> {
+    let mut file = SiteFile::parse(DEFAULT_SITES)?;
+
+    let path = config.path.as_path();
+    if path.exists() {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read site file {}: {:?}", path.display(), e))?;
+        let overrides = SiteFile::parse(&text)
+            .map_err(|e| anyhow!("Invalid site file {}: {:?}", path.display(), e))?;
+        file = file.merge(overrides);
+    } else {
+        println!(
+            "Site file {} not found, using embedded sites",
+            path.display()
+        );
+    }
+
+    for (name, base_url) in &config.base_urls {
+        file = file.with_base_url(name, base_url);
+    }
+
+    let sites = Sites::resolve(file)?;
+    for (name, site) in sites.iter() {
+        println!("Site loaded: {} ({})", name, site.base_url);
+    }
+
+    Ok(Arc::new(sites))
+}
+
+// 서버 시작 시 한 번 등록
+pub fn init_sites(sites: Arc<Sites>) {
+    if SITES.set(sites).is_err() {
+        eprintln!("Sites are already initialized");
+    }
+}
+
+pub fn sites() -> Arc<Sites> {
+    Arc::clone(SITES.get().unwrap_or(&DEFAULT_SITES_CONFIG))
+}
+
+pub fn page_url(page: SitePage) -> Result<String> {
+    sites()
+        .site(page.site)
+        .and_then(|site| site.page_url(page.page))
+        .ok_or_else(|| {
+            eprintln!("Site page is not configured: {}", page);
+            anyhow!("Site page is not configured: {}", page)
+        })
+}
+
+// 화면에서 읽은 href 를 사이트 주소 기준으로 변환
+pub fn site_url(site: &str, href: &str) -> Result<String> {
+    sites()
+        .site(site)
+        .ok_or_else(|| anyhow!("Site is not configured: {}", site))?
+        .join(href)
+}
@@ -0,0 +1,262 @@
+use crate::models::driver::supervisor::{DriverState, DriverStatus};
+use anyhow::{Result, anyhow};
+use chrono::Utc;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::process::{Child, Command};
+use tokio::sync::{Mutex, Notify, watch};
+use tokio::task::JoinHandle;
+use tokio::time::timeout;
+
+const PROBE_INTERVAL: Duration = Duration::from_secs(2);
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+const MAX_PROBE_FAILURES: u32 = 3;
+const STARTUP_GRACE: Duration = Duration::from_secs(15);
+const MIN_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+// chromedriver 프로세스 감시. /status 폴링, 종료 시 backoff 재시작, SIGTERM 시 프로세스 그룹 종료
+pub struct DriverSupervisor {
+    path: String,
+    port: u16,
+    child: Mutex<Option<Child>>,
+    status_tx: watch::Sender<DriverStatus>,
+    shutting_down: AtomicBool,
+    shutdown_notify: Notify,
+}
+
+impl DriverSupervisor {
+    pub fn new(path: &str, port: u16) -> Arc<Self> {
+        let (status_tx, _) = watch::channel(DriverStatus::new(port));
+
+        Arc::new(DriverSupervisor {
+            path: path.to_string(),
+            port,
+            child: Mutex::new(None),
+            status_tx,
+            shutting_down: AtomicBool::new(false),
+            shutdown_notify: Notify::new(),
+        })
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        self.status_tx.borrow().clone()
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<DriverStatus> {
+        self.status_tx.subscribe()
+    }
+
+    pub fn spawn(self: &Arc<Self>) -> JoinHandle<()> {
+        let supervisor = Arc::clone(self);
+        tokio::spawn(async move { supervisor.run().await })
+    }
+
+    async fn run(&self) {
+        let mut backoff = MIN_BACKOFF;
+
+        while !self.is_shutting_down() {
+            let pid = match self.start_process().await {
+                Ok(pid) => pid,
+                Err(e) => {
+                    eprintln!("Failed to start ChromeDriver: {:?}", e);
+                    self.set_state(DriverState::Restarting, Some(format!("{:?}", e)));
+                    self.sleep_or_shutdown(backoff).await;
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                    continue;
+                }
+            };
+            println!("ChromeDriver started on port {} (pid {:?})", self.port, pid);
+
+            if self.is_shutting_down() {
+                self.kill_process().await;
+                break;
+            }
+
+            if self.monitor().await {
+                backoff = MIN_BACKOFF;
+            }
+
+            if self.is_shutting_down() {
+                break;
+            }
+
+            self.kill_process().await;
+            self.status_tx.send_modify(|status| status.restarts += 1);
+            self.set_state(DriverState::Restarting, None);
+            eprintln!("ChromeDriver restarting in {:?}", backoff);
+            self.sleep_or_shutdown(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    // 프로세스가 죽거나 연속으로 probe 에 실패할 때까지 감시. 한 번이라도 ready 였다면 true
+    async fn monitor(&self) -> bool {
+        let started = tokio::time::Instant::now();
+        let mut was_ready = false;
+        let mut failures = 0;
+
+        loop {
+            self.sleep_or_shutdown(PROBE_INTERVAL).await;
+            if self.is_shutting_down() {
+                return was_ready;
+            }
+
+            if let Some(exit) = self.try_wait().await {
+                eprintln!("ChromeDriver exited: {}", exit);
+                self.set_state(DriverState::Unhealthy, Some(exit));
+                return was_ready;
+            }
+
+            match probe_status(self.port).await {
+                Ok(true) => {
+                    failures = 0;
+                    was_ready = true;
+                    self.set_state(DriverState::Ready, None);
+                }
+                Ok(false) => {
+                    failures += 1;
+                    self.set_state(DriverState::Unhealthy, Some("driver not ready".to_string()));
+                }
+                Err(e) => {
+                    // 기동 직후에는 포트가 아직 열리지 않았을 수 있음
+                    if !was_ready && started.elapsed() < STARTUP_GRACE {
+                        continue;
+                    }
+                    failures += 1;
+                    self.set_state(DriverState::Unhealthy, Some(format!("{:?}", e)));
+                }
+            }
+
+            if failures >= MAX_PROBE_FAILURES {
+                eprintln!(
+                    "ChromeDriver failed {} consecutive health probes",
+                    MAX_PROBE_FAILURES
+                );
+                return was_ready;
+            }
+        }
+    }
+
+    async fn start_process(&self) -> Result<Option<u32>> {
+        self.set_state(DriverState::Starting, None);
+
+        let mut command = Command::new(&self.path);
+        command
+            .arg(format!("--port={}", self.port))
+            .kill_on_drop(true);
+        // chrome 자식 프로세스까지 한 번에 종료하기 위해 별도 프로세스 그룹으로 실행
+        #[cfg(unix)]
+        command.process_group(0);
+
+        let child = command.spawn().map_err(|e| {
+            eprintln!("Failed to start ChromeDriver: {:?}", e);
+            anyhow!("Failed to start ChromeDriver: {:?}", e)
+        })?;
+
+        let pid = child.id();
+        self.status_tx.send_modify(|status| status.pid = pid);
+        *self.child.lock().await = Some(child);
+
+        Ok(pid)
+    }
+
+    async fn try_wait(&self) -> Option<String> {
+        let mut child = self.child.lock().await;
+
+        match child.as_mut().map(|process| process.try_wait()) {
+            Some(Ok(Some(status))) => Some(status.to_string()),
+            Some(Ok(None)) => None,
+            Some(Err(e)) => Some(format!("{:?}", e)),
+            None => Some("process missing".to_string()),
+        }
+    }
+
+    async fn kill_process(&self) {
+        let Some(mut process) = self.child.lock().await.take() else {
+            return;
+        };
+
+        #[cfg(unix)]
+        if let Some(pid) = process.id() {
+            // SAFETY: chromedriver 는 process_group(0) 으로 실행되어 pgid == pid
+            unsafe {
+                libc::kill(-(pid as i32), libc::SIGKILL);
+            }
+        }
+
+        if let Ok(Some(_status)) = process.try_wait() {
+            println!("ChromeDriver process already terminated");
+        } else if let Err(e) = process.kill().await {
+            eprintln!("Failed to kill ChromeDriver: {:?}", e);
+        } else {
+            println!("ChromeDriver process terminated");
+        }
+
+        self.status_tx.send_modify(|status| status.pid = None);
+    }
+
+    pub async fn shutdown(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        self.shutdown_notify.notify_waiters();
+
+        self.kill_process().await;
+        self.set_state(DriverState::Stopped, None);
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    async fn sleep_or_shutdown(&self, duration: Duration) {
+        tokio::select! {
+            _ = tokio::time::sleep(duration) => {}
+            _ = self.shutdown_notify.notified() => {}
+        }
+    }
+
+    fn set_state(&self, state: DriverState, last_error: Option<String>) {
+        self.status_tx.send_if_modified(|status| {
+            if status.state == state && last_error.is_none() {
+                return false;
+            }
+            status.state = state;
+            if last_error.is_some() {
+                status.last_error = last_error;
+            }
+            status.updated_at = Utc::now();
+            true
+        });
+    }
+}
+
+// chromedriver GET /status 의 value.ready 확인
+async fn probe_status(port: u16) -> Result<bool> {
+    let response = timeout(PROBE_TIMEOUT, async {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+        stream
+            .write_all(b"GET /status HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await?;
+
+        let mut buf = Vec::new();
+        stream.read_to_end(&mut buf).await?;
+        Ok::<_, std::io::Error>(buf)
+    })
+    .await
+    .map_err(|_| anyhow!("Timed out probing ChromeDriver /status"))?
+    .map_err(|e| anyhow!("Failed to probe ChromeDriver /status: {:?}", e))?;
+
+    let response = String::from_utf8_lossy(&response);
+    let body = response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body)
+        .ok_or_else(|| anyhow!("Malformed ChromeDriver /status response"))?;
+
+    let json: serde_json::Value = serde_json::from_str(body.trim())
+        .map_err(|e| anyhow!("Failed to parse ChromeDriver /status: {:?}", e))?;
+
+    Ok(json["value"]["ready"].as_bool().unwrap_or(false))
+}
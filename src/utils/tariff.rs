@@ -0,0 +1,539 @@
+use crate::models::{
+    error::response_errors_def::ErrorResponseCode,
+    handler::pp::{
+        load_profile::LoadBand,
+        user_info::{contract_vec, purpose_vec},
+    },
+    tariff::{
+        compare::{MonthlyUsage, PlanTotal, SkippedPlan, TariffComparison},
+        rates::{
+            EnergyRates, PlanRates, RateTable, Season, TariffBill, TariffInput,
+            fuel_cost_adjustment_per_kwh, rate_tables,
+        },
+    },
+};
+
+// 청구 월에 적용되는 요금표. rate_version 이 있으면 그 버전을 사용
+pub fn select_rate_table(input: &TariffInput) -> Result<RateTable, ErrorResponseCode> {
+    let tables = rate_tables();
+
+    let table = match &input.rate_version {
+        Some(version) => tables.into_iter().find(|table| table.version == version),
+        None => tables
+            .into_iter()
+            .rev()
+            .find(|table| table.effective_from <= input.claim_month),
+    };
+
+    table.ok_or_else(|| {
+        eprintln!(
+            "Rate table not found: version {:?}, claim month {}",
+            input.rate_version, input.claim_month
+        );
+        ErrorResponseCode::TARIFF_RATE_NOT_FOUND
+    })
+}
+
+// 한전 요금 계산. 원 미만 절사, 부가가치세 반올림, 기금·청구금액 10원 미만 절사
+pub fn calculate_bill(input: &TariffInput) -> Result<TariffBill, ErrorResponseCode> {
+    let table = select_rate_table(input)?;
+    let plan = table
+        .plan(input.contract_type_id, input.purpose_id)
+        .ok_or_else(|| {
+            eprintln!(
+                "Tariff plan not found: contract_type_id {}, purpose_id {}",
+                input.contract_type_id, input.purpose_id
+            );
+            ErrorResponseCode::TARIFF_RATE_NOT_FOUND
+        })?;
+
+    let fuel_cost_per_kwh = fuel_cost_adjustment_per_kwh(input.claim_month).ok_or_else(|| {
+        eprintln!("Fuel cost adjustment not found: {}", input.claim_month);
+        ErrorResponseCode::TARIFF_RATE_NOT_FOUND
+    })?;
+
+    let season = Season::of(input.claim_month);
+    let usage_kwh = match (&input.band_usage, input.usage_kwh) {
+        (Some(band_usage), _) => band_usage.total(),
+        (None, Some(usage_kwh)) => usage_kwh,
+        (None, None) => return Err(ErrorResponseCode::TARIFF_USAGE_REQUIRED),
+    };
+
+    let energy_charge = match &plan.energy {
+        EnergyRates::Seasonal(rates) => usage_kwh * rates.of(season),
+        EnergyRates::TimeOfUse(rates) => {
+            // 계시별 요금은 시간대별 사용량이 필요
+            let band_usage = input
+                .band_usage
+                .ok_or(ErrorResponseCode::TARIFF_USAGE_REQUIRED)?;
+            let rates = rates.of(season);
+
+            band_usage.off_peak * rates.rate(LoadBand::OffPeak)
+                + band_usage.mid_peak * rates.rate(LoadBand::MidPeak)
+                + band_usage.peak * rates.rate(LoadBand::Peak)
+        }
+    };
+
+    let demand_kw = input.billing_demand_kw.unwrap_or(input.contract_power);
+    let basic_charge = (demand_kw * plan.basic_charge_per_kw).floor() as i64;
+    let energy_charge = energy_charge.floor() as i64;
+    let climate_environment_charge = (usage_kwh * table.climate_environment_per_kwh).floor() as i64;
+    let fuel_cost_adjustment = (usage_kwh * fuel_cost_per_kwh).floor() as i64;
+
+    let electricity_charge =
+        basic_charge + energy_charge + climate_environment_charge + fuel_cost_adjustment;
+    let vat = (electricity_charge as f64 * table.vat_rate).round() as i64;
+    let power_industry_fund =
+        floor_to_ten((electricity_charge as f64 * table.power_industry_fund_rate).floor() as i64);
+    let total = floor_to_ten(electricity_charge + vat + power_industry_fund);
+
+    Ok(TariffBill {
+        rate_version: table.version,
+        plan: plan.name,
+        season,
+        usage_kwh,
+        basic_charge,
+        energy_charge,
+        climate_environment_charge,
+        fuel_cost_adjustment,
+        electricity_charge,
+        vat,
+        power_industry_fund,
+        total,
+    })
+}
+
+fn floor_to_ten(amount: i64) -> i64 {
+    amount.div_euclid(10) * 10
+}
+
+// 계약종별 구분 ("산업용(을)" -> "산업용")
+fn contract_family(contract_type_id: i16) -> Option<&'static str> {
+    contract_vec()
+        .into_iter()
+        .find(|(_, id)| *id == contract_type_id)
+        .map(|(name, _)| name.split('(').next().unwrap_or(name).trim())
+}
+
+// 공급전압 구분 ("고압A선택2" -> "고압A")
+fn voltage_group(purpose_id: i16) -> Option<&'static str> {
+    purpose_vec()
+        .into_iter()
+        .find(|(_, id)| *id == purpose_id)
+        .map(|(name, _)| name.split("선택").next().unwrap_or(name))
+}
+
+// 갑/을 구분 계약전력 (kW). 갑은 미만, 을은 이상
+const EUL_MIN_CONTRACT_POWER: f64 = 300.0;
+
+// 계약종별의 계약전력 조건 ("산업용(갑)I" 는 300kW 미만, "산업용(을)" 은 300kW 이상)
+fn contract_power_fits(contract_type_id: i16, contract_power: f64) -> bool {
+    match contract_vec()
+        .into_iter()
+        .find(|(_, id)| *id == contract_type_id)
+        .map(|(name, _)| name)
+    {
+        Some(name) if name.contains("(갑)") => contract_power < EUL_MIN_CONTRACT_POWER,
+        Some(name) if name.contains("(을)") => contract_power >= EUL_MIN_CONTRACT_POWER,
+        _ => true,
+    }
+}
+
+// 용도 전환이 가능한 계약종별 (업종 조건을 만족하는 경우)
+fn other_use_family(family: &str) -> Option<&'static str> {
+    match family {
+        "일반용" => Some("산업용"),
+        "산업용" => Some("일반용"),
+        _ => None,
+    }
+}
+
+// 계약전력과 공급전압으로 선택할 수 있는 요금제 (현재 요금제 포함).
+// include_other_use 이면 일반용 <-> 산업용 요금제도 포함
+pub fn eligible_plans(
+    contract_type_id: i16,
+    purpose_id: i16,
+    contract_power: f64,
+    include_other_use: bool,
+) -> Vec<PlanRates> {
+    let Some(table) = rate_tables().pop() else {
+        return Vec::new();
+    };
+    let (Some(family), Some(voltage)) =
+        (contract_family(contract_type_id), voltage_group(purpose_id))
+    else {
+        return table
+            .plan(contract_type_id, purpose_id)
+            .into_iter()
+            .copied()
+            .collect();
+    };
+    let other_family = other_use_family(family).filter(|_| include_other_use);
+
+    table
+        .plans
+        .iter()
+        .filter(|plan| {
+            if (plan.contract_type_id, plan.purpose_id) == (contract_type_id, purpose_id) {
+                return true;
+            }
+
+            let family_fits = match contract_family(plan.contract_type_id) {
+                Some(plan_family) => plan_family == family || Some(plan_family) == other_family,
+                None => false,
+            };
+            family_fits
+                && voltage_group(plan.purpose_id) == Some(voltage)
+                && contract_power_fits(plan.contract_type_id, contract_power)
+        })
+        .copied()
+        .collect()
+}
+
+// 월별 사용량으로 한 요금제의 합계 계산
+fn plan_total(
+    contract_type_id: i16,
+    purpose_id: i16,
+    contract_power: f64,
+    months: &[MonthlyUsage],
+) -> Result<(&'static str, i64), ErrorResponseCode> {
+    let mut plan = "";
+    let mut total = 0;
+
+    for month in months {
+        let bill = calculate_bill(&TariffInput {
+            contract_type_id,
+            purpose_id,
+            contract_power,
+            billing_demand_kw: month.billing_demand_kw,
+            claim_month: month.claim_month,
+            usage_kwh: month.usage_kwh,
+            band_usage: month.band_usage,
+            rate_version: None,
+        })?;
+        plan = bill.plan;
+        total += bill.total;
+    }
+
+    Ok((plan, total))
+}
+
+// 계시별 요금제를 월 합계만으로 계산할 수 없을 때의 사유
+const NEEDS_BAND_USAGE: &str = "needs time-band usage";
+
+// 계시별 요금제는 모든 월에 시간대별 사용량이 있어야 계산 가능
+fn needs_band_usage(plan: &PlanRates, months: &[MonthlyUsage]) -> bool {
+    matches!(plan.energy, EnergyRates::TimeOfUse(_))
+        && months.iter().any(|month| month.band_usage.is_none())
+}
+
+// 현재 요금제와 대체 요금제들의 기간 합계 비교
+pub fn compare_plans(
+    contract_type_id: i16,
+    purpose_id: i16,
+    contract_power: f64,
+    months: &[MonthlyUsage],
+    include_other_use: bool,
+) -> Result<TariffComparison, ErrorResponseCode> {
+    if months.is_empty() {
+        return Err(ErrorResponseCode::TARIFF_USAGE_REQUIRED);
+    }
+
+    let (current_plan, current_total) =
+        plan_total(contract_type_id, purpose_id, contract_power, months)?;
+
+    let mut alternatives = Vec::new();
+    let mut skipped = Vec::new();
+    let eligible = eligible_plans(
+        contract_type_id,
+        purpose_id,
+        contract_power,
+        include_other_use,
+    );
+    for alt in eligible {
+        if (alt.contract_type_id, alt.purpose_id) == (contract_type_id, purpose_id) {
+            continue;
+        }
+
+        let skip = |reason| SkippedPlan {
+            contract_type_id: alt.contract_type_id,
+            purpose_id: alt.purpose_id,
+            plan: alt.name,
+            reason,
+        };
+
+        if needs_band_usage(&alt, months) {
+            skipped.push(skip(NEEDS_BAND_USAGE));
+            continue;
+        }
+
+        match plan_total(alt.contract_type_id, alt.purpose_id, contract_power, months) {
+            Ok((plan, total)) => alternatives.push(PlanTotal {
+                contract_type_id: alt.contract_type_id,
+                purpose_id: alt.purpose_id,
+                plan,
+                total,
+                savings: current_total - total,
+            }),
+            Err(e) => {
+                eprintln!(
+                    "Skipping plan ({}, {}) in comparison: {:?}",
+                    alt.contract_type_id, alt.purpose_id, e.message
+                );
+                skipped.push(skip(e.message));
+            }
+        }
+    }
+
+    alternatives.sort_by_key(|plan| std::cmp::Reverse(plan.savings));
+
+    Ok(TariffComparison {
+        months: months.len(),
+        current: PlanTotal {
+            contract_type_id,
+            purpose_id,
+            plan: current_plan,
+            total: current_total,
+            savings: 0,
+        },
+        alternatives,
+        skipped,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::tariff::rates::{BandUsage, FUEL_COST_ADJUSTMENTS};
+    use chrono::NaiveDate;
+
+    fn input(
+        contract_type_id: i16,
+        purpose_id: i16,
+        contract_power: f64,
+        claim_month: (i32, u32),
+    ) -> TariffInput {
+        TariffInput {
+            contract_type_id,
+            purpose_id,
+            contract_power,
+            billing_demand_kw: None,
+            claim_month: NaiveDate::from_ymd_opt(claim_month.0, claim_month.1, 1).unwrap(),
+            usage_kwh: None,
+            band_usage: None,
+            rate_version: None,
+        }
+    }
+
+    #[test]
+    fn seasonal_low_voltage_bill() {
+        // 일반용(갑)I 저압 10kW, 2025년 8월 (여름철) 2,000kWh
+        let bill = calculate_bill(&TariffInput {
+            usage_kwh: Some(2000.0),
+            ..input(13, 23, 10.0, (2025, 8))
+        })
+        .ok()
+        .expect("bill");
+
+        assert_eq!(bill.rate_version, "2025-07-01");
+        assert_eq!(bill.season, Season::Summer);
+        assert_eq!(bill.basic_charge, 61_600); // 10kW x 6,160
+        assert_eq!(bill.energy_charge, 270_000); // 2,000kWh x 135.0
+        assert_eq!(bill.climate_environment_charge, 18_000); // 2,000kWh x 9.0
+        assert_eq!(bill.fuel_cost_adjustment, 10_000); // 2,000kWh x 5.0
+        assert_eq!(bill.electricity_charge, 359_600);
+        assert_eq!(bill.vat, 35_960);
+        // 359,600 x 2.7% = 9,709.2 -> 10원 미만 절사
+        assert_eq!(bill.power_industry_fund, 9_700);
+        assert_eq!(bill.total, 405_260);
+    }
+
+    #[test]
+    fn time_of_use_bill_uses_band_rates_and_billing_demand() {
+        // 산업용(을) 고압A 선택I, 요금적용전력 420kW, 2024년 12월 (겨울철)
+        let bill = calculate_bill(&TariffInput {
+            billing_demand_kw: Some(420.0),
+            band_usage: Some(BandUsage {
+                off_peak: 100_000.0,
+                mid_peak: 60_000.0,
+                peak: 40_000.0,
+            }),
+            ..input(8, 11, 500.0, (2024, 12))
+        })
+        .ok()
+        .expect("bill");
+
+        assert_eq!(bill.rate_version, "2024-10-24");
+        assert_eq!(bill.season, Season::Winter);
+        assert_eq!(bill.usage_kwh, 200_000.0);
+        assert_eq!(bill.basic_charge, 3_032_400); // 420kW x 7,220
+        // 100,000 x 117.1 + 60,000 x 162.6 + 40,000 x 219.4
+        assert_eq!(bill.energy_charge, 30_242_000);
+        assert_eq!(bill.climate_environment_charge, 1_800_000);
+        assert_eq!(bill.fuel_cost_adjustment, 1_000_000);
+        assert_eq!(bill.electricity_charge, 36_074_400);
+        assert_eq!(bill.vat, 3_607_440);
+        // 36,074,400 x 3.2% = 1,154,380.8
+        assert_eq!(bill.power_industry_fund, 1_154_380);
+        assert_eq!(bill.total, 40_836_220);
+    }
+
+    #[test]
+    fn vat_is_rounded_and_fund_and_total_are_floored_to_ten_won() {
+        // 산업용(갑)I 저압 7kW, 2024년 4월 (봄·가을철) 1,238kWh
+        let bill = calculate_bill(&TariffInput {
+            usage_kwh: Some(1238.0),
+            ..input(6, 23, 7.0, (2024, 4))
+        })
+        .ok()
+        .expect("bill");
+
+        assert_eq!(bill.rate_version, "2023-11-09");
+        assert_eq!(bill.energy_charge, 94_583); // 1,238 x 76.4 = 94,583.2
+        assert_eq!(bill.electricity_charge, 150_765);
+        assert_eq!(bill.vat, 15_077); // 15,076.5 반올림
+        assert_eq!(bill.power_industry_fund, 5_570); // 150,765 x 3.7% = 5,578.3
+        assert_eq!(bill.total, 171_410); // 171,412
+    }
+
+    #[test]
+    fn rate_table_follows_the_claim_month() {
+        let band_usage = Some(BandUsage {
+            off_peak: 1000.0,
+            mid_peak: 1000.0,
+            peak: 1000.0,
+        });
+        let bill = |claim_month| {
+            calculate_bill(&TariffInput {
+                band_usage,
+                ..input(8, 11, 100.0, claim_month)
+            })
+            .ok()
+            .expect("bill")
+        };
+
+        assert_eq!(bill((2024, 6)).rate_version, "2023-11-09");
+        assert_eq!(bill((2024, 7)).rate_version, "2024-07-01");
+        assert_eq!(bill((2024, 10)).rate_version, "2024-07-01");
+        assert_eq!(bill((2024, 11)).rate_version, "2024-10-24");
+        assert_eq!(bill((2025, 7)).rate_version, "2025-07-01");
+
+        // 2024-10-24 개정으로 산업용(을) 전력량요금 16.1원/kWh 인상 (같은 봄·가을철 비교)
+        let before = bill((2024, 9));
+        let after = bill((2024, 10));
+        let revised = bill((2025, 9));
+        assert_eq!(before.energy_charge, after.energy_charge);
+        assert_eq!(revised.energy_charge - before.energy_charge, 48_300);
+
+        let pinned = calculate_bill(&TariffInput {
+            band_usage,
+            rate_version: Some("2023-11-09".to_string()),
+            ..input(8, 11, 100.0, (2025, 9))
+        })
+        .ok()
+        .expect("bill");
+        assert_eq!(pinned.energy_charge, before.energy_charge);
+    }
+
+    fn plan_ids(plans: Vec<PlanRates>) -> Vec<(i16, i16)> {
+        plans
+            .into_iter()
+            .map(|plan| (plan.contract_type_id, plan.purpose_id))
+            .collect()
+    }
+
+    #[test]
+    fn eligible_plans_follow_contract_power_and_voltage() {
+        // 산업용(갑)I 고압A 400kW -> 산업용(을) 고압A 선택I~III
+        assert_eq!(
+            plan_ids(eligible_plans(6, 10, 400.0, false)),
+            vec![(8, 11), (8, 12), (8, 13)]
+        );
+        // 300kW 미만이면 을 요금제 대상 아님
+        assert!(eligible_plans(6, 10, 200.0, false).is_empty());
+        // 공급전압이 다르면 제외 (고압B)
+        assert_eq!(
+            plan_ids(eligible_plans(8, 15, 1000.0, false)),
+            vec![(8, 15), (8, 16), (8, 17)]
+        );
+        // 저압 30kW 는 같은 용도의 저압 요금제만
+        assert_eq!(
+            plan_ids(eligible_plans(13, 23, 30.0, false)),
+            vec![(13, 23)]
+        );
+    }
+
+    #[test]
+    fn other_use_plans_are_opt_in() {
+        assert_eq!(
+            plan_ids(eligible_plans(13, 23, 30.0, true)),
+            vec![(13, 23), (6, 23)]
+        );
+        assert_eq!(
+            plan_ids(eligible_plans(8, 11, 500.0, true)),
+            vec![(15, 11), (15, 12), (8, 11), (8, 12), (8, 13)]
+        );
+    }
+
+    #[test]
+    fn time_of_use_plans_need_band_usage_for_every_month() {
+        let total_only = MonthlyUsage {
+            claim_month: NaiveDate::from_ymd_opt(2025, 8, 1).unwrap(),
+            usage_kwh: Some(2000.0),
+            band_usage: None,
+            billing_demand_kw: None,
+        };
+        let with_bands = MonthlyUsage {
+            band_usage: Some(BandUsage {
+                off_peak: 1000.0,
+                mid_peak: 600.0,
+                peak: 400.0,
+            }),
+            ..total_only.clone()
+        };
+        let table = rate_tables().pop().unwrap();
+        let seasonal = *table.plan(13, 23).unwrap();
+        let time_of_use = *table.plan(8, 11).unwrap();
+
+        assert!(!needs_band_usage(
+            &seasonal,
+            std::slice::from_ref(&total_only)
+        ));
+        assert!(!needs_band_usage(
+            &time_of_use,
+            std::slice::from_ref(&with_bands)
+        ));
+        assert!(needs_band_usage(
+            &time_of_use,
+            &[with_bands.clone(), total_only.clone()]
+        ));
+
+        let comparison = compare_plans(15, 11, 500.0, &[with_bands], true)
+            .ok()
+            .expect("comparison");
+        assert_eq!(comparison.alternatives.len(), 4);
+        assert!(comparison.skipped.is_empty());
+
+        let comparison = compare_plans(13, 23, 30.0, &[total_only], true)
+            .ok()
+            .expect("comparison");
+        assert_eq!(comparison.alternatives.len(), 1);
+        assert!(comparison.skipped.is_empty());
+    }
+
+    #[test]
+    fn rate_tables_are_in_effective_date_order() {
+        let tables = rate_tables();
+        assert!(
+            tables
+                .windows(2)
+                .all(|pair| pair[0].effective_from < pair[1].effective_from)
+        );
+        assert!(
+            FUEL_COST_ADJUSTMENTS
+                .windows(2)
+                .all(|pair| pair[0].0 < pair[1].0)
+        );
+        assert!(calculate_bill(&input(13, 23, 10.0, (2022, 1))).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+// 사이트의 로딩 레이어 동작. 화면 전환 직후 표시되었다가 사라짐
+function showLoading() {
+    document.getElementById('backgroundLayer').style.display = 'block';
+}
+
+function hideLoading() {
+    document.getElementById('backgroundLayer').style.display = 'none';
+}
+
+window.addEventListener('load', function () {
+    setTimeout(hideLoading, 100);
+});